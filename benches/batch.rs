@@ -52,7 +52,161 @@ fn events_batch(c: &mut Criterion) {
 
     // clear buffer
     events.query_blocking::<f64>().unwrap();
+
+    group.bench_function("try_new_event", |b| {
+        b.iter(|| black_box(events.try_new_event::<f64>(64.0)))
+    });
+
+    // clear buffer
+    events.query_blocking::<f64>().unwrap();
+
+    group.bench_function("try_new_event unregistered", |b| {
+        b.iter(|| black_box(events.try_new_event::<f32>(64.0)))
+    });
+}
+
+fn events_multiple(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multiple events");
+
+    let events = create_backend();
+    let values = vec![64.0; 1000];
+
+    group.bench_function("new_event 1000", |b| {
+        b.iter(|| {
+            for value in &values {
+                new_event_bench(&events, *value);
+            }
+        })
+    });
+
+    // clear buffer
+    events.query_blocking::<f64>().unwrap();
+
+    group.bench_function("new_events 1000", |b| {
+        b.iter(|| black_box(events.new_events::<f64>(values.iter().copied()).unwrap()))
+    });
+
+    // clear buffer
+    events.query_blocking::<f64>().unwrap();
+}
+
+fn create_sample_backends() -> (Backend, Backend) {
+    let mut all = Backend::new();
+    all.register_store::<f64>(SlotType::All).unwrap();
+
+    let mut sample = Backend::new();
+    sample.register_store::<f64>(SlotType::Sample(10)).unwrap();
+
+    (all, sample)
+}
+
+fn fill_and_query_many(events: &Backend) {
+    for i in 0..1000 {
+        events.new_event::<f64>(f64::from(i)).unwrap();
+    }
+
+    for event in events.query::<f64>().unwrap() {
+        _ = black_box(event);
+    }
+}
+
+fn events_sample(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sample");
+
+    let (all, sample) = create_sample_backends();
+
+    group.bench_function("all", |b| b.iter(|| fill_and_query_many(&all)));
+
+    group.bench_function("sample 10", |b| b.iter(|| fill_and_query_many(&sample)));
+}
+
+fn create_small_backends() -> (Backend, Backend) {
+    let mut max = Backend::new();
+    max.register_store::<f64>(SlotType::Max(4)).unwrap();
+
+    let mut inline = Backend::new();
+    inline.register_store_inline::<f64, 4>().unwrap();
+
+    (max, inline)
+}
+
+fn fill_and_query(events: &Backend) {
+    for i in 0..8 {
+        events.new_event::<f64>(f64::from(i)).unwrap();
+    }
+
+    for event in events.query::<f64>().unwrap() {
+        _ = black_box(event);
+    }
+}
+
+fn events_small_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("small batch");
+
+    let (max, inline) = create_small_backends();
+
+    group.bench_function("max", |b| b.iter(|| fill_and_query(&max)));
+
+    group.bench_function("inline", |b| b.iter(|| fill_and_query(&inline)));
+}
+
+/// Distinct event type for each `N`.
+struct Many<const N: usize>;
+
+macro_rules! register_many {
+    ($events:ident, $($n:literal)+) => {
+        $(
+            $events.register_store::<Many<$n>>(SlotType::All).unwrap();
+        )+
+    };
+}
+
+fn create_many_backend() -> Backend {
+    let mut events = Backend::new();
+
+    register_many!(
+        events,
+        0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+        32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63
+    );
+
+    // only a single type has pending events
+    events.new_event(Many::<63>).unwrap();
+
+    events
+}
+
+fn events_pending(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pending");
+
+    let events = create_many_backend();
+
+    group.bench_function("pending types", |b| {
+        b.iter(|| black_box(events.pending_types()))
+    });
+}
+
+fn events_many_types(c: &mut Criterion) {
+    let mut group = c.benchmark_group("many types");
+
+    let events = create_many_backend();
+
+    // last registered type
+    group.bench_function("event 64 types", |b| {
+        b.iter(|| events.new_event(Many::<63>).unwrap())
+    });
+
+    // clear buffer
+    events.query_blocking::<Many<63>>().unwrap();
 }
 
-criterion_group!(benches, events_batch);
+criterion_group!(
+    benches,
+    events_batch,
+    events_multiple,
+    events_small_batch,
+    events_sample,
+    events_pending,
+    events_many_types
+);
 criterion_main!(benches);
@@ -0,0 +1,48 @@
+use std::sync::OnceLock;
+
+use eventsys::{EventBackend, EventBackendBuilder, SlotType};
+
+/// Example Event
+#[derive(Debug, Clone, Copy)]
+struct MouseEvent {
+    _x: i32,
+    _y: i32,
+}
+
+/// Example Event
+#[derive(Debug, Clone, Copy)]
+struct KeyboardEvent {
+    _key: char,
+    _up: bool,
+}
+
+static EVENTS: OnceLock<EventBackend> = OnceLock::new();
+
+fn get_events() -> &'static EventBackend {
+    // build the global event system on first use
+    EVENTS.get_or_init(|| {
+        EventBackendBuilder::default()
+            .listener::<KeyboardEvent>(|event| println!("Event listener: {event:?}"))
+            .store::<MouseEvent>(SlotType::All)
+            .build()
+            .unwrap()
+    })
+}
+
+fn main() {
+    // trigger events
+    get_events()
+        .new_event(KeyboardEvent {
+            _key: 'A',
+            _up: false,
+        })
+        .unwrap();
+    get_events()
+        .new_event(MouseEvent { _x: 0, _y: 20 })
+        .unwrap();
+
+    // query mouse event
+    for mouse_events in get_events().query::<MouseEvent>().unwrap() {
+        println!("Batched event: {mouse_events:?}");
+    }
+}
@@ -0,0 +1,30 @@
+use eventsys::{EventBackend, SlotType};
+
+/// Example Event
+#[derive(Debug, Clone, Copy)]
+struct MouseEvent {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    // create event system
+    let mut system = EventBackend::default();
+
+    // register event type and keep a typed handle to it
+    let mouse = system.subscribe_store::<MouseEvent>(SlotType::All).unwrap();
+
+    for frame in 0..3 {
+        // trigger mouse events
+        for i in 0..frame + 1 {
+            mouse
+                .new_event(&system, MouseEvent { x: i, y: frame })
+                .unwrap();
+        }
+
+        // poll mouse events once per frame
+        for event in mouse.query(&system).unwrap() {
+            println!("Frame {frame}: ({}, {})", event.x, event.y);
+        }
+    }
+}
@@ -1,17 +1,35 @@
 use std::{
-    any::TypeId,
-    collections::VecDeque,
-    panic::RefUnwindSafe,
-    sync::{atomic::AtomicBool, MutexGuard},
+    any::{Any, TypeId},
+    cell::Cell,
+    collections::{HashMap, VecDeque},
+    error::Error,
+    hash::Hash,
+    panic::{AssertUnwindSafe, RefUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::{Duration, Instant},
 };
 
+/// A type-erased event, as it is stored by an [`EventBackend`].
 pub type Event<const SIZE: usize> = anythingy::Thing<SIZE>;
 
+#[cfg(feature = "async")]
+use crate::tasks::{BoxFuture, TaskQueue};
 use crate::{
+    descriptor::EventDescriptor,
+    diagnostics::{Diagnostics, TypeDiagnostics},
     err::{EventError, EventSizeError, Value},
+    guard::DisableGuard,
+    listener::{ListenerFlow, ListenerHandle, ListenerId, Subscription},
     map::RegisteredMap,
-    query::{Query, UnblockingQuery},
-    slot::{Slot, SlotType},
+    merge::EventMerge,
+    outcome::EventOutcome,
+    query::{PeekQuery, Query, UnblockingQuery},
+    set::EventSet,
+    slot::{Pushed, Slot, SlotGuard, SlotType},
+    store::StoreSubscription,
     DEFAULT_EVENT_SIZE,
 };
 
@@ -22,8 +40,23 @@ use crate::{
 ///
 /// Events can be any type. For efficient event dispatching, all event types have to be the same size.
 /// For very big events or Dynamically Sized Types (DSTs) events can be boxed.
+///
+/// # Concurrency
+/// Registering event types and listeners requires `&mut self`, while triggering, querying and bulk operations
+/// like [`EventBackend::disable_all`] only require `&self`. The set of registered event types is therefore frozen,
+/// while the `EventBackend` is shared between threads. To register event types while other threads are using the
+/// `EventBackend`, it has to be wrapped in a lock, e.g. a [`RwLock`](std::sync::RwLock).
+/// Bulk operations never observe a partially registered event type.
 pub struct EventBackend<const EVENT_SIZE: usize = DEFAULT_EVENT_SIZE> {
     pub(crate) registered: RegisteredMap<EVENT_SIZE>,
+    /// Listeners called for events of every type.
+    global: Vec<GlobalListener<EVENT_SIZE>>,
+    /// Called with the payload of panicking listeners.
+    panic_hook: Option<PanicHook>,
+    /// Pauses processing of events of every type, independent of the per type flags.
+    paused: AtomicBool,
+    #[cfg(feature = "async")]
+    tasks: Option<Arc<TaskQueue>>,
 }
 
 impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
@@ -32,6 +65,11 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
     pub const fn new() -> Self {
         Self {
             registered: RegisteredMap::new(),
+            global: Vec::new(),
+            panic_hook: None,
+            paused: AtomicBool::new(false),
+            #[cfg(feature = "async")]
+            tasks: None,
         }
     }
 
@@ -40,6 +78,7 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
     /// # Errors
     /// Returns an `EventError`, if
     ///     - the type can not be used as an event
+    ///     - the slot type has a degenerate parameter, like `SlotType::Max(0)` or `SlotType::Sample(0)`
     ///
     /// # Example
     /// ```rust
@@ -59,128 +98,226 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
             )));
         }
 
-        let id = TypeId::of::<T>();
+        if matches!(typ, SlotType::Max(0)) {
+            return Err(EventError::invalid_slot_param(
+                "SlotType::Max needs to store at least one event",
+            ));
+        }
 
-        let slot = Slot::new(typ);
+        if matches!(typ, SlotType::Bounded(0)) {
+            return Err(EventError::invalid_slot_param(
+                "SlotType::Bounded needs to store at least one event",
+            ));
+        }
 
-        if let Some(registered) = self.registered.get_mut(&id) {
-            registered.slot = Some(slot);
-            return Ok(());
+        if matches!(typ, SlotType::Top(0, _) | SlotType::Bottom(0, _)) {
+            return Err(EventError::invalid_slot_param(
+                "SlotType::Top and SlotType::Bottom need to store at least one event",
+            ));
         }
 
-        let mut registered = Registered::new();
-        registered.slot = Some(slot);
-        _ = self.registered.insert(id, registered);
+        if matches!(typ, SlotType::Sample(0)) {
+            return Err(EventError::invalid_slot_param(
+                "SlotType::Sample needs to store every nth event, with n of at least 1",
+            ));
+        }
+
+        if matches!(
+            typ,
+            SlotType::Reservoir(0) | SlotType::ReservoirSeeded(0, _)
+        ) {
+            return Err(EventError::invalid_slot_param(
+                "SlotType::Reservoir needs to store at least one event",
+            ));
+        }
+
+        self.insert_slot::<T>(Slot::new(typ));
 
         Ok(())
     }
 
-    /// Registers a function that gets called, if an event with the matching type is triggered.
-    /// Returns the number of listener registered for this type of event.
+    /// Registers a new type of event, like [`EventBackend::register_store`].
+    /// Returns a typed handle, that allows triggering and querying events without naming the event type again.
     ///
     /// # Errors
     /// Returns an `EventError`, if
     ///     - the type can not be used as an event
+    ///     - the slot type has a degenerate parameter, like `SlotType::Max(0)` or `SlotType::Sample(0)`
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::EventBackend;
+    /// # use eventsys::{EventBackend, SlotType};
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// let listener = |event: &u32| {
+    /// let clicks = system.subscribe_store::<u32>(SlotType::All).unwrap();
+    ///
+    /// clicks.new_event(&system, 42).unwrap();
+    ///
+    /// for click in clicks.query(&system).unwrap() {
     ///     // handle event
-    /// };
+    /// }
+    /// # }
+    /// ```
+    pub fn subscribe_store<T: 'static>(
+        &mut self,
+        typ: SlotType<T>,
+    ) -> Result<StoreSubscription<T, EVENT_SIZE>, EventError<T>> {
+        self.register_store(typ)?;
+
+        Ok(StoreSubscription::new())
+    }
+
+    /// Registers a new type of event, that stores up to `capacity` events. See [`SlotType::Max`].
+    /// Registered events can be querried in a batch.
     ///
-    /// system.register_listener::<u32>(listener).unwrap();
+    /// When the store is full, the oldest event gets evicted and handed to `on_evict`.
+    /// The callback runs while the store is locked, so it should be cheap.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - `capacity` is 0
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store_max_cb::<u32>(16, |evicted| {
+    ///     // log or forward evicted event
+    /// }).unwrap();
     /// # }
     /// ```
-    pub fn register_listener<T: 'static>(
+    pub fn register_store_max_cb<T: 'static>(
         &mut self,
-        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
-    ) -> Result<usize, EventSizeError> {
+        capacity: usize,
+        on_evict: impl Fn(T) + Send + Sync + 'static,
+    ) -> Result<(), EventError<T>> {
         // check if T can be used as an event
         if !Event::<EVENT_SIZE>::fitting::<T>() {
-            return Err(EventSizeError::new(
+            return Err(EventError::event_size_empty(EventSizeError::new(
                 EVENT_SIZE,
                 Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        if capacity == 0 {
+            return Err(EventError::invalid_slot_param(
+                "capacity needs to be at least one event",
             ));
         }
 
-        let id = TypeId::of::<T>();
+        self.insert_slot::<T>(Slot::max_with_evict::<T>(capacity, on_evict));
 
-        let map_f = move |event: &Event<EVENT_SIZE>| {
-            let value = event.get_ref::<T>();
-            listener(value);
-        };
+        Ok(())
+    }
+
+    /// Registers a new type of event, that stores up to `capacity` distinct events.
+    /// Registered events can be querried in a batch.
+    ///
+    /// Triggering an event, that is equal to an already stored event, moves the stored event to the most recent position.
+    /// When the store is full, the least recently triggered event gets replaced.
+    ///
+    /// Each new event is compared with every stored event, so triggering an event takes `O(capacity)` time.
+    /// This is best suited for a small `capacity`.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - `capacity` is 0
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store_lru::<u32>(16).unwrap();
+    /// # }
+    /// ```
+    pub fn register_store_lru<T: Eq + 'static>(
+        &mut self,
+        capacity: usize,
+    ) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
 
-        if let Some(registered) = self.registered.get_mut(&id) {
-            registered.listener.push(Box::new(map_f));
-            return Ok(registered.listener.len());
+        if capacity == 0 {
+            return Err(EventError::invalid_slot_param(
+                "capacity needs to be at least one event",
+            ));
         }
 
-        let mut registered = Registered::new();
-        registered.listener.push(Box::new(map_f));
-        _ = self.registered.insert(id, registered);
+        self.insert_slot::<T>(Slot::unique::<T>(capacity));
 
-        Ok(1)
+        Ok(())
     }
 
-    /// Triggers a new event, calling all registered event listener. If event was registered to be stored,
-    /// event gets saved to be queried later after each listener was called.
+    /// Registers a new type of event, that stores the last `N` events.
+    /// Registered events can be querried in a batch.
+    ///
+    /// The events are stored in an inline array of `N` events, that is allocated once on registration,
+    /// so storing events never allocates. When the store is full, the oldest event gets replaced.
     ///
     /// # Errors
     /// Returns an `EventError`, if
     ///     - the type can not be used as an event
-    ///     - the event is not registered for storage and no event listener was set
+    ///     - `N` is 0
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::{EventBackend, SlotType};
+    /// # use eventsys::EventBackend;
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// system.register_store::<u32>(SlotType::First).unwrap();
-    ///
-    /// system.new_event::<u32>(42).unwrap();
+    /// // keep the last 4 inputs
+    /// system.register_store_inline::<char, 4>().unwrap();
     /// # }
     /// ```
-    pub fn new_event<T: 'static>(&self, value: T) -> Result<(), EventError<T, Value>> {
+    pub fn register_store_inline<T: 'static, const N: usize>(
+        &mut self,
+    ) -> Result<(), EventError<T>> {
         // check if T can be used as an event
         if !Event::<EVENT_SIZE>::fitting::<T>() {
-            let err = EventSizeError::new(EVENT_SIZE, Event::<EVENT_SIZE>::size_requirement::<T>());
-            return Err(EventError::event_size(value, err));
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
         }
 
-        let id = TypeId::of::<T>();
-
-        if let Some(registered) = self.registered.get(&id) {
-            registered.handle_event(Event::new(value));
-        } else {
-            return Err(EventError::unregisted_event(value));
+        if N == 0 {
+            return Err(EventError::invalid_slot_param(
+                "N needs to be at least one event",
+            ));
         }
 
+        self.insert_slot::<T>(Slot::inline::<N>());
+
         Ok(())
     }
 
-    /// Returns an iterator over each event with the matching event type.
+    /// Registers a new type of event, that stores each distinct event once together with the number of its occurrences.
+    /// Counted events can be querried with [`EventBackend::query_counts`].
+    ///
+    /// Events are looked up by their hash, so counting an event does not compare it with every distinct event.
+    /// Counted events are kept apart from stored events, so any other query yields no events.
     ///
     /// # Errors
-    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
-    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::{EventBackend, SlotType};
+    /// # use eventsys::EventBackend;
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// # system.register_store::<u32>(SlotType::All);
-    /// let query = system.query::<u32>().unwrap();
-    ///
-    /// for event in query {
-    ///     // handle event
-    /// }
+    /// system.register_store_counting::<char>().unwrap();
     /// # }
     /// ```
-    pub fn query<T: 'static>(&self) -> Result<UnblockingQuery<T, EVENT_SIZE>, EventError<T>> {
+    pub fn register_store_counting<T: Eq + Hash + 'static>(&mut self) -> Result<(), EventError<T>> {
         // check if T can be used as an event
         if !Event::<EVENT_SIZE>::fitting::<T>() {
             return Err(EventError::event_size_empty(EventSizeError::new(
@@ -189,42 +326,30 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
             )));
         }
 
-        let id = TypeId::of::<T>();
+        self.insert_slot::<T>(Slot::counting::<T>());
 
-        self.registered.get(&id).map_or_else(
-            || Err(EventError::unregisted_event_empty()),
-            |registed| {
-                registed.events_clone().map_or_else(
-                    || Err(EventError::registered_without_store()),
-                    |events| Ok(UnblockingQuery::new(events)),
-                )
-            },
-        )
+        Ok(())
     }
 
-    /// Returns an iterator over each event with the matching event type.
-    ///
-    /// # Warning
-    /// Holding the query will block access to this event type, but will not clone the underlying data. For not-blocking but cloning query, see [`EventBackend::query`].
+    /// Registers a new type of event, that only stores the event with the smallest key.
+    /// A new event replaces the stored event, if its key is strictly smaller.
     ///
     /// # Errors
-    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
-    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::{EventBackend, SlotType};
+    /// # use eventsys::EventBackend;
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// # system.register_store::<u32>(SlotType::All);
-    /// let query = system.query_blocking::<u32>().unwrap();
-    ///
-    /// for event in query {
-    ///     // handle event
-    /// }
+    /// system.register_store_min_by::<(u32, &'static str), _>(|(priority, _)| *priority).unwrap();
     /// # }
     /// ```
-    pub fn query_blocking<T: 'static>(&self) -> Result<Query<T, EVENT_SIZE>, EventError<T>> {
+    pub fn register_store_min_by<T: 'static, K: Ord>(
+        &mut self,
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> Result<(), EventError<T>> {
         // check if T can be used as an event
         if !Event::<EVENT_SIZE>::fitting::<T>() {
             return Err(EventError::event_size_empty(EventSizeError::new(
@@ -233,34 +358,31 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
             )));
         }
 
-        let id = TypeId::of::<T>();
+        let cmp = move |current: &T, new: &T| key(new) < key(current);
+        self.insert_slot::<T>(Slot::new(SlotType::CmpFn(Box::new(cmp))));
 
-        self.registered.get(&id).map_or_else(
-            || Err(EventError::unregisted_event_empty()),
-            |registed| {
-                registed.events().map_or_else(
-                    || Err(EventError::registered_without_store()),
-                    |events| Ok(Query::new(events)),
-                )
-            },
-        )
+        Ok(())
     }
 
-    /// Disables specific event from being processed.
+    /// Registers a new type of event, that only stores the event with the largest key.
+    /// A new event replaces the stored event, if its key is strictly larger.
     ///
     /// # Errors
-    /// Returns an `UnregisteredEventTypeError`, if the event type is not registered or no event listener was registered.
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::{EventBackend, SlotType};
+    /// # use eventsys::EventBackend;
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// # system.register_store::<u64>(SlotType::All);
-    /// system.disable::<u64>().unwrap();
+    /// system.register_store_max_by::<(u32, &'static str), _>(|(priority, _)| *priority).unwrap();
     /// # }
     /// ```
-    pub fn disable<T: 'static>(&self) -> Result<(), EventError<T>> {
+    pub fn register_store_max_by<T: 'static, K: Ord>(
+        &mut self,
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> Result<(), EventError<T>> {
         // check if T can be used as an event
         if !Event::<EVENT_SIZE>::fitting::<T>() {
             return Err(EventError::event_size_empty(EventSizeError::new(
@@ -269,47 +391,104 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
             )));
         }
 
-        let id = TypeId::of::<T>();
+        let cmp = move |current: &T, new: &T| key(new) > key(current);
+        self.insert_slot::<T>(Slot::new(SlotType::CmpFn(Box::new(cmp))));
 
-        match self.registered.get(&id) {
-            Some(registered) => registered.disable(),
-            None => return Err(EventError::unregisted_event_empty()),
+        Ok(())
+    }
+
+    /// Registers a new type of event, that only stores the most recent event for each key.
+    /// A new event replaces the stored event with an equal key, otherwise it is stored after all other events.
+    ///
+    /// Stored events are looked up by their key, so the key of each new event is only computed once.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store_latest_by_key::<(u32, f32), _>(|(sensor, _)| *sensor).unwrap();
+    /// # }
+    /// ```
+    pub fn register_store_latest_by_key<T: 'static, K: Hash + Eq + Send + 'static>(
+        &mut self,
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
         }
 
+        self.insert_slot::<T>(Slot::latest_by_key::<T, K>(key));
+
         Ok(())
     }
 
-    /// Disables all events.
+    /// Registers a new type of event, that folds all events into a single accumulated value, instead of storing them.
+    /// The first event after the accumulated value was taken gets folded into a clone of `init`.
+    ///
+    /// Use [`query_accumulated`](Self::query_accumulated) to take the accumulated value.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::{EventBackend, SlotType};
+    /// # use eventsys::EventBackend;
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// system.disable_all();
+    /// system.register_store_accumulate::<u32, u64>(0, |sum, value| *sum += u64::from(*value)).unwrap();
     /// # }
     /// ```
-    pub fn disable_all(&self) {
-        for registered in self.registered.values() {
-            registered.disable();
+    pub fn register_store_accumulate<T: 'static, A: Clone + Send + Sync + 'static>(
+        &mut self,
+        init: A,
+        fold: fn(&mut A, &T),
+    ) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
         }
+
+        self.insert_slot::<T>(Slot::accumulate::<T, A>(init, fold));
+
+        Ok(())
     }
 
-    /// Enables specific event for processesing.
+    /// Sets a handler that gets called with all stored events of the matching event type, when the `EventBackend` is dropped.
+    /// Replaces a previously set handler.
     ///
     /// # Errors
-    /// Returns an `UnregisteredEventTypeError`, if the event type is not registered or no event listener was registered.
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
     ///
     /// # Example
     /// ```rust
     /// # use eventsys::{EventBackend, SlotType};
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// # system.register_store::<u64>(SlotType::Last);
-    /// system.enable::<u64>().unwrap();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    ///
+    /// system.set_flush_on_drop::<u32>(|events| {
+    ///     // persist events
+    /// }).unwrap();
     /// # }
     /// ```
-    pub fn enable<T: 'static>(&self) -> Result<(), EventError<T>> {
+    pub fn set_flush_on_drop<T: 'static>(
+        &mut self,
+        handler: impl FnOnce(Vec<T>) + Send + Sync + 'static,
+    ) -> Result<(), EventError<T>> {
         // check if T can be used as an event
         if !Event::<EVENT_SIZE>::fitting::<T>() {
             return Err(EventError::event_size_empty(EventSizeError::new(
@@ -320,40 +499,2569 @@ impl<const EVENT_SIZE: usize> EventBackend<EVENT_SIZE> {
 
         let id = TypeId::of::<T>();
 
-        match self.registered.get(&id) {
-            Some(registered) => registered.enable(),
-            None => return Err(EventError::unregisted_event_empty()),
+        let Some(registered) = self.registered.get_mut(&id) else {
+            return Err(EventError::unregisted_event_empty());
+        };
+
+        if !registered.has_store() {
+            return Err(EventError::registered_without_store());
         }
 
+        let map_f = move |events: VecDeque<Event<EVENT_SIZE>>| {
+            handler(events.into_iter().map(Event::get).collect());
+        };
+
+        registered.flush = Some(Box::new(map_f));
+
         Ok(())
     }
 
-    /// Enables all events.
+    /// Applies the registrations of all descriptors in order.
+    /// Returns the result of each registration.
     ///
     /// # Example
     /// ```rust
-    /// # use eventsys::{EventBackend, SlotType};
+    /// # use eventsys::{EventBackend, EventDescriptor, SlotType};
     /// # fn main() {
     /// # let mut system = EventBackend::default();
-    /// system.enable_all();
+    /// let descriptors = [
+    ///     EventDescriptor::new(|system: &mut EventBackend| {
+    ///         system.register_store::<u32>(SlotType::All)?;
+    ///         Ok(())
+    ///     }),
+    ///     EventDescriptor::new(|system: &mut EventBackend| {
+    ///         system.register_listener::<u64>(|event: &u64| {
+    ///             // handle event
+    ///         })?;
+    ///         Ok(())
+    ///     }),
+    /// ];
+    ///
+    /// for result in system.bulk_register(&descriptors) {
+    ///     result.unwrap();
+    /// }
     /// # }
     /// ```
-    pub fn enable_all(&self) {
-        for registered in self.registered.values() {
-            registered.enable();
-        }
+    pub fn bulk_register(
+        &mut self,
+        descriptors: &[EventDescriptor<EVENT_SIZE>],
+    ) -> Vec<Result<(), Box<dyn Error>>> {
+        descriptors
+            .iter()
+            .map(|descriptor| descriptor.register(self))
+            .collect()
     }
 
-    /// Frees allocated memory for batch events.
-    ///
-    /// # Warn
-    /// All events that are not consumed will get dropped.
-    pub fn cleanup(&mut self) {
-        for registered in self.registered.values_mut() {
-            registered.cleanup();
-        }
+    fn insert_slot<T: 'static>(&mut self, slot: Slot<EVENT_SIZE>) {
+        let registered = self.registered.get_or_insert_with(TypeId::of::<T>(), || {
+            Registered::new(std::any::type_name::<T>())
+        });
+
+        registered.slot = Some(slot);
     }
-}
+
+    /// Returns the registration of the event type, registering the type without a store first, if it was not registered yet.
+    ///
+    /// # Errors
+    /// Returns an `EventSizeError`, if the type can not be used as an event.
+    fn registered_or_insert<T: 'static>(
+        &mut self,
+    ) -> Result<&mut Registered<EVENT_SIZE>, EventSizeError> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            ));
+        }
+
+        Ok(self.registered.get_or_insert_with(TypeId::of::<T>(), || {
+            Registered::new(std::any::type_name::<T>())
+        }))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// Returns the number of listener registered for this type of event.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let listener = |event: &u32| {
+    ///     // handle event
+    /// };
+    ///
+    /// system.register_listener::<u32>(listener).unwrap();
+    /// # }
+    /// ```
+    pub fn register_listener<T: 'static>(
+        &mut self,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<usize, EventSizeError> {
+        self.register_listener_handle(listener)?;
+
+        let id = TypeId::of::<T>();
+
+        Ok(self
+            .registered
+            .get(&id)
+            .map_or(0, Registered::listener_count))
+    }
+
+    /// Registers a function that can mutate its captured state, that gets called, if an event with the matching type is triggered.
+    /// Returns the number of listener registered for this type of event.
+    ///
+    /// Calls to the listener are serialized with a lock, other listeners for the same type still run concurrently.
+    /// Triggering an event of the same type from within the listener deadlocks.
+    ///
+    /// A panic inside the listener gets caught like for any other listener, the captured state stays usable afterwards.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let mut counter = 0;
+    ///
+    /// system.register_listener_mut::<u32>(move |event: &u32| {
+    ///     counter += 1;
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_listener_mut<T: 'static>(
+        &mut self,
+        listener: impl FnMut(&T) + Send + 'static,
+    ) -> Result<usize, EventSizeError> {
+        let listener = Mutex::new(listener);
+
+        self.register_listener(move |event: &T| {
+            // a panicking listener poisons the lock, but leaves its state usable
+            let mut listener = listener.lock().unwrap_or_else(PoisonError::into_inner);
+            listener(event);
+        })
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// Returns an id, that allows removing the listener again with [`EventBackend::remove_listener`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let id = system.register_listener_handle::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert!(system.remove_listener::<u32>(id).unwrap());
+    /// # }
+    /// ```
+    pub fn register_listener_handle<T: 'static>(
+        &mut self,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<ListenerId, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value);
+        };
+
+        let listener = self
+            .registered_or_insert::<T>()?
+            .push_listener(Box::new(map_f));
+
+        Ok(ListenerId::new(TypeId::of::<T>(), listener))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// Listeners with a higher priority get called first, listeners with the same priority get called in registration order.
+    /// All other listeners have a priority of `0`.
+    /// Returns an id, that allows removing the listener again with [`EventBackend::remove_listener`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // update state
+    /// }).unwrap();
+    ///
+    /// system.register_listener_with_priority::<u32>(10, |event: &u32| {
+    ///     // log event, before the state gets updated
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_listener_with_priority<T: 'static>(
+        &mut self,
+        priority: i32,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<ListenerId, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value);
+        };
+
+        let listener = self
+            .registered_or_insert::<T>()?
+            .push_listener_with_priority(Box::new(map_f), priority);
+
+        Ok(ListenerId::new(TypeId::of::<T>(), listener))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// Return `true` to consume the event, so it is not passed on to the following listeners, else return `false`.
+    /// Returns an id, that allows removing the listener again with [`EventBackend::remove_listener`].
+    ///
+    /// A consumed event is not passed to the remaining listeners, but still gets stored, if the type is registered to store events.
+    /// One-shot listeners registered with [`EventBackend::register_listener_once`] are still called for consumed events.
+    /// Listeners are called by descending priority, see [`EventBackend::register_listener_with_priority`].
+    /// The listener has a priority of `0`, so it can only consume events before listeners with a lower priority,
+    /// or with the same priority, that got registered later. See [`EventBackend::register_listener_filtering_with_priority`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener_filtering::<u32>(|event: &u32| {
+    ///     // handle small events here, pass on the others
+    ///     *event <= 10
+    /// }).unwrap();
+    ///
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // only called for events larger than 10
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_listener_filtering<T: 'static>(
+        &mut self,
+        listener: impl Fn(&T) -> bool + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<ListenerId, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value)
+        };
+
+        let listener = self
+            .registered_or_insert::<T>()?
+            .push_filtering(Box::new(map_f), 0);
+
+        Ok(ListenerId::new(TypeId::of::<T>(), listener))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// Return `true` to consume the event, so it is not passed on to the following listeners, else return `false`.
+    /// Returns an id, that allows removing the listener again with [`EventBackend::remove_listener`].
+    ///
+    /// Like [`EventBackend::register_listener_filtering`], but the listener gets called before all listeners with a lower priority,
+    /// so a high priority can be used to consume events before any other listener sees them.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // gameplay, only called for events not consumed by the ui
+    /// }).unwrap();
+    ///
+    /// system.register_listener_filtering_with_priority::<u32>(10, |event: &u32| {
+    ///     // ui, consumes small events
+    ///     *event <= 10
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_listener_filtering_with_priority<T: 'static>(
+        &mut self,
+        priority: i32,
+        listener: impl Fn(&T) -> bool + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<ListenerId, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value)
+        };
+
+        let listener = self
+            .registered_or_insert::<T>()?
+            .push_filtering(Box::new(map_f), priority);
+
+        Ok(ListenerId::new(TypeId::of::<T>(), listener))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// Returns a [`Subscription`], that removes the listener when dropped.
+    ///
+    /// Dropping the subscription only marks the listener as inactive, as triggering events only requires `&self`.
+    /// A listener that is called on another thread, while its subscription gets dropped, may still finish this call.
+    /// The listener itself is freed with the next registration of a listener for this event type.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let subscription = system.subscribe::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// system.new_event::<u32>(1).unwrap();
+    ///
+    /// // listener does not get called anymore
+    /// drop(subscription);
+    /// system.new_event::<u32>(2).unwrap();
+    /// # }
+    /// ```
+    pub fn subscribe<T: 'static>(
+        &mut self,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<Subscription, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value);
+        };
+
+        let active = Arc::new(AtomicBool::new(true));
+
+        let listener = self
+            .registered_or_insert::<T>()?
+            .push_subscription(Box::new(map_f), Arc::clone(&active));
+
+        Ok(Subscription::new(
+            ListenerId::new(TypeId::of::<T>(), listener),
+            active,
+        ))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// The listener decides with the returned [`ListenerFlow`], if it should keep getting called.
+    /// Returns an id, that allows removing the listener again with [`EventBackend::remove_listener`].
+    ///
+    /// Returning [`ListenerFlow::Remove`] only marks the listener as inactive, the removal takes effect after
+    /// all listeners for the current event have run. Events triggered concurrently on other threads may still
+    /// call the listener, while it is being removed.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, ListenerFlow};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener_ctl::<u32>(|event: &u32| {
+    ///     // stop listening after the shutdown event
+    ///     if *event == 0 {
+    ///         ListenerFlow::Remove
+    ///     } else {
+    ///         ListenerFlow::Keep
+    ///     }
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_listener_ctl<T: 'static>(
+        &mut self,
+        listener: impl Fn(&T) -> ListenerFlow + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<ListenerId, EventSizeError> {
+        let active = Arc::new(AtomicBool::new(true));
+        let active_c = Arc::clone(&active);
+
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+
+            if listener(value) == ListenerFlow::Remove {
+                active_c.store(false, Ordering::Release);
+            }
+        };
+
+        let listener = self
+            .registered_or_insert::<T>()?
+            .push_subscription(Box::new(map_f), active);
+
+        Ok(ListenerId::new(TypeId::of::<T>(), listener))
+    }
+
+    /// Registers a function that gets called for events of every registered type, before the listeners of the type itself.
+    /// The listener receives the type-erased event together with the `TypeId` of the event type.
+    ///
+    /// Global listeners are only called for events triggered with [`EventBackend::new_event`] and its variants,
+    /// for event types that are registered and enabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::any::TypeId;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_global_listener(|id, event| {
+    ///     if id == TypeId::of::<u32>() {
+    ///         let value = event.get_ref::<u32>();
+    ///         // log event
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn register_global_listener(
+        &mut self,
+        listener: impl Fn(TypeId, &Event<EVENT_SIZE>) + Send + Sync + RefUnwindSafe + 'static,
+    ) {
+        self.global.push(Box::new(listener));
+    }
+
+    /// Sets a function that gets called, whenever a listener panics.
+    /// The hook receives the `TypeId` of the event type and the panic payload.
+    ///
+    /// Panics of listeners are caught, so a panicking listener does not affect other listeners.
+    /// Without a hook, these panics are not reported. A panic inside the hook itself is caught as well.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.set_panic_hook(|id, payload| {
+    ///     if let Some(message) = payload.downcast_ref::<&str>() {
+    ///         // report panic
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn set_panic_hook(
+        &mut self,
+        hook: impl Fn(TypeId, Box<dyn Any + Send>) + Send + Sync + 'static,
+    ) {
+        self.panic_hook = Some(Box::new(hook));
+    }
+
+    /// Removes the listener with the matching id.
+    /// Returns `true`, if the listener got removed, or `false`, if no listener with this id exists (anymore).
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let id = system.register_listener_handle::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert!(system.remove_listener::<u32>(id).unwrap());
+    /// assert!(!system.remove_listener::<u32>(id).unwrap());
+    /// # }
+    /// ```
+    pub fn remove_listener<T: 'static>(&mut self, id: ListenerId) -> Result<bool, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let type_id = TypeId::of::<T>();
+
+        let Some(registered) = self.registered.get_mut(&type_id) else {
+            return Err(EventError::unregisted_event_empty());
+        };
+
+        // ids of other event types never match
+        if id.type_id() != type_id {
+            return Ok(false);
+        }
+
+        Ok(registered.remove_listener(id.listener()))
+    }
+
+    /// Registers a function that gets called once, for the next event with the matching type.
+    /// The listener is removed after it got called.
+    ///
+    /// The returned [`ListenerHandle`] allows cancelling the listener before it got called.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let handle = system.register_listener_once::<u32>(|event: &u32| {
+    ///     // handle response
+    /// }).unwrap();
+    ///
+    /// system.new_event::<u32>(42).unwrap();
+    /// assert!(handle.is_done());
+    /// # }
+    /// ```
+    pub fn register_listener_once<T: 'static>(
+        &mut self,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<ListenerHandle, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value);
+        };
+
+        let done = Arc::new(AtomicBool::new(false));
+        let handle = ListenerHandle::new(Arc::clone(&done));
+
+        self.registered_or_insert::<T>()?
+            .push_once(done, Box::new(map_f));
+
+        Ok(handle)
+    }
+
+    /// Registers a function under a name, that gets called, if an event with the matching type is triggered.
+    /// Returns the number of listener registered for this type of event.
+    ///
+    /// Names are unique per event type. Registering a name that is already in use replaces its listener,
+    /// see [`EventBackend::replace_named_listener`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_named_listener::<u32>("on_click", |event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    /// # }
+    /// ```
+    pub fn register_named_listener<T: 'static>(
+        &mut self,
+        name: &str,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<usize, EventSizeError> {
+        self.replace_named_listener(name, listener)?;
+
+        let id = TypeId::of::<T>();
+
+        Ok(self
+            .registered
+            .get(&id)
+            .map_or(0, Registered::listener_count))
+    }
+
+    /// Replaces the listener registered under a name for the matching event type.
+    /// Returns `true`, if a listener got replaced, or `false`, if the listener got newly registered.
+    ///
+    /// Other listeners of the same event type are not affected and keep their order.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_named_listener::<u32>("on_click", |event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// // reload handler
+    /// let replaced = system.replace_named_listener::<u32>("on_click", |event: &u32| {
+    ///     // handle event differently
+    /// }).unwrap();
+    /// assert!(replaced);
+    /// # }
+    /// ```
+    pub fn replace_named_listener<T: 'static>(
+        &mut self,
+        name: &str,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<bool, EventSizeError> {
+        let map_f = move |event: &Event<EVENT_SIZE>| {
+            let value = event.get_ref::<T>();
+            listener(value);
+        };
+
+        Ok(self
+            .registered_or_insert::<T>()?
+            .set_named_listener(name, Box::new(map_f)))
+    }
+
+    /// Registers an async function that gets called, if an event with the matching type is triggered.
+    /// Returns the number of listener registered for this type of event.
+    ///
+    /// **The event system never polls the returned futures.**
+    /// Triggering an event only queues the future, it does not run until it is taken with [`EventBackend::take_tasks`]
+    /// and spawned on an async runtime. Futures, that are never taken, stay queued together with their cloned events.
+    /// There is no guarantee in which order futures complete.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// let listener = |event: u32| -> eventsys::BoxFuture<'static, ()> {
+    ///     Box::pin(async move {
+    ///         // handle event
+    ///     })
+    /// };
+    ///
+    /// system.register_async_listener::<u32>(listener).unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn register_async_listener<T: Clone + 'static>(
+        &mut self,
+        listener: impl Fn(T) -> BoxFuture<'static, ()> + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Result<usize, EventSizeError> {
+        let tasks = self.tasks.get_or_insert_with(Arc::default).clone();
+
+        self.register_listener(move |event: &T| {
+            tasks.push(listener(event.clone()));
+        })
+    }
+
+    /// Takes all futures, that got queued by async event listeners.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let system = EventBackend::default();
+    /// for task in system.take_tasks() {
+    ///     // spawn task on async runtime
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn take_tasks(&self) -> Vec<BoxFuture<'static, ()>> {
+        self.tasks
+            .as_ref()
+            .map_or_else(Vec::new, |tasks| tasks.take())
+    }
+
+    /// Triggers a new event, calling all registered event listener. If event was registered to be stored,
+    /// event gets saved to be queried later after each listener was called.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - the event is not registered for storage and no event listener was set
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::First).unwrap();
+    ///
+    /// system.new_event::<u32>(42).unwrap();
+    /// # }
+    /// ```
+    pub fn new_event<T: 'static>(&self, value: T) -> Result<(), EventError<T, Value>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            let err = EventSizeError::new(EVENT_SIZE, Event::<EVENT_SIZE>::size_requirement::<T>());
+            return Err(EventError::event_size(value, err));
+        }
+
+        let id = TypeId::of::<T>();
+
+        if let Some(registered) = self.registered.get(&id) {
+            _ = self.handle_event(id, registered, Event::new(value));
+        } else {
+            return Err(EventError::unregisted_event(value));
+        }
+
+        Ok(())
+    }
+
+    /// Triggers a new event and reports what happened to it. See [`EventBackend::new_event`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - the event is not registered for storage and no event listener was set
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::First).unwrap();
+    ///
+    /// let outcome = system.new_event_report::<u32>(1).unwrap();
+    /// assert!(outcome.stored);
+    ///
+    /// let outcome = system.new_event_report::<u32>(2).unwrap();
+    /// assert!(outcome.dropped_by_slot);
+    /// # }
+    /// ```
+    pub fn new_event_report<T: 'static>(
+        &self,
+        value: T,
+    ) -> Result<EventOutcome, EventError<T, Value>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            let err = EventSizeError::new(EVENT_SIZE, Event::<EVENT_SIZE>::size_requirement::<T>());
+            return Err(EventError::event_size(value, err));
+        }
+
+        let id = TypeId::of::<T>();
+
+        let Some(registered) = self.registered.get(&id) else {
+            return Err(EventError::unregisted_event(value));
+        };
+
+        Ok(self.handle_event(id, registered, Event::new(value)))
+    }
+
+    /// Triggers a new event. Returns `true`, if the event got dispatched, see [`EventBackend::new_event`].
+    ///
+    /// Unlike [`EventBackend::new_event`], no error is built if the event can not be dispatched,
+    /// the value is dropped instead. Useful in hot loops, that ignore failures anyway.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    ///
+    /// assert!(system.try_new_event::<u32>(42));
+    /// assert!(!system.try_new_event::<u64>(42));
+    /// # }
+    /// ```
+    // failing to dispatch is expected to be ignored by the caller
+    #[allow(clippy::must_use_candidate)]
+    pub fn try_new_event<T: 'static>(&self, value: T) -> bool {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return false;
+        }
+
+        let id = TypeId::of::<T>();
+
+        let Some(registered) = self.registered.get(&id) else {
+            return false;
+        };
+
+        _ = self.handle_event(id, registered, Event::new(value));
+
+        true
+    }
+
+    /// Triggers a new event and returns the number of listeners, that got called and returned without panicking.
+    /// See [`EventBackend::new_event`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - the event is not registered for storage and no event listener was set
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert_eq!(system.new_event_counted::<u32>(42).unwrap(), 1);
+    /// # }
+    /// ```
+    pub fn new_event_counted<T: 'static>(&self, value: T) -> Result<usize, EventError<T, Value>> {
+        let outcome = self.new_event_report(value)?;
+
+        Ok(outcome.listeners_invoked - outcome.listeners_panicked)
+    }
+
+    /// Calls the global listeners, before the event is handled by its registered type.
+    #[inline]
+    fn handle_event(
+        &self,
+        id: TypeId,
+        registered: &Registered<EVENT_SIZE>,
+        event: Event<EVENT_SIZE>,
+    ) -> EventOutcome {
+        // check if events should be processed at all
+        if self.is_paused() {
+            return EventOutcome::default();
+        }
+
+        let panicked = Cell::new(0);
+        let on_panic = |payload| {
+            panicked.set(panicked.get() + 1);
+            self.on_panic(id, payload);
+        };
+
+        let global = if registered.is_enabled() {
+            self.call_global(id, &event, &on_panic)
+        } else {
+            0
+        };
+
+        let mut outcome = registered.handle_event(event, &on_panic);
+        outcome.listeners_invoked += global;
+        outcome.listeners_panicked = panicked.get();
+
+        outcome
+    }
+
+    /// Calls all global listeners with the event. Returns the number of called listeners.
+    #[inline]
+    fn call_global(&self, id: TypeId, event: &Event<EVENT_SIZE>, on_panic: OnPanic<'_>) -> usize {
+        for listener in &self.global {
+            if let Err(payload) = std::panic::catch_unwind(|| listener(id, event)) {
+                on_panic(payload);
+            }
+        }
+
+        self.global.len()
+    }
+
+    /// Calls the listeners for every value, before storing all events at once. Returns the number of dispatched events.
+    #[inline]
+    fn handle_events<T: 'static>(
+        &self,
+        id: TypeId,
+        registered: &Registered<EVENT_SIZE>,
+        values: impl IntoIterator<Item = T>,
+    ) -> usize {
+        // check if events for this registered type should be processed
+        if self.is_paused() || !registered.is_enabled() {
+            return 0;
+        }
+
+        let on_panic = |payload| self.on_panic(id, payload);
+
+        let events = values
+            .into_iter()
+            .map(|value| {
+                let event = Event::new(value);
+
+                self.call_global(id, &event, &on_panic);
+                registered.call_listeners(&event, &on_panic);
+
+                event
+            })
+            .collect::<Vec<_>>();
+
+        let dispatched = events.len();
+
+        // store all events under a single lock
+        registered.push_all(events);
+
+        dispatched
+    }
+
+    /// Passes the payload of a panicking listener to the panic hook.
+    #[inline]
+    fn on_panic(&self, id: TypeId, payload: Box<dyn Any + Send>) {
+        let Some(hook) = &self.panic_hook else {
+            return;
+        };
+
+        // a panicking hook should not affect the remaining listeners
+        _ = std::panic::catch_unwind(AssertUnwindSafe(|| hook(id, payload)));
+    }
+
+    /// Triggers a new event, that is derived from `source`. See [`EventBackend::new_event`].
+    ///
+    /// Calling this for several event types fans out a single source value into multiple events.
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - the event is not registered for storage and no event listener was set
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.register_store::<bool>(SlotType::All).unwrap();
+    ///
+    /// let raw: u64 = 42;
+    /// system.new_event_mapped(&raw, |raw| *raw as u32).unwrap();
+    /// system.new_event_mapped(&raw, |raw| *raw > 10).unwrap();
+    /// # }
+    /// ```
+    pub fn new_event_mapped<S, T: 'static>(
+        &self,
+        source: &S,
+        map: impl FnOnce(&S) -> T,
+    ) -> Result<(), EventError<T, Value>> {
+        self.new_event(map(source))
+    }
+
+    /// Triggers a new event for every value, see [`EventBackend::new_event`]. Returns the number of dispatched events.
+    ///
+    /// The event type is only checked and looked up once, and all events are stored at once after the
+    /// listeners were called for every value, which makes this cheaper than calling [`EventBackend::new_event`] for every value.
+    /// While the event type is disabled or the `EventBackend` is paused, no events are dispatched.
+    ///
+    /// # Errors
+    /// Returns an `EventError` with the first value, if
+    ///     - the type can not be used as an event
+    ///     - the event is not registered for storage and no event listener was set
+    ///
+    /// All other values get dropped in this case. If `values` is empty, no error is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    ///
+    /// assert_eq!(system.new_events::<u32>([1, 2, 3]).unwrap(), 3);
+    /// # }
+    /// ```
+    #[doc(alias = "new_events_batch")]
+    pub fn new_events<T: 'static>(
+        &self,
+        values: impl IntoIterator<Item = T>,
+    ) -> Result<usize, EventError<T, Value>> {
+        let mut values = values.into_iter();
+
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            let Some(value) = values.next() else {
+                return Ok(0);
+            };
+
+            let err = EventSizeError::new(EVENT_SIZE, Event::<EVENT_SIZE>::size_requirement::<T>());
+            return Err(EventError::event_size(value, err));
+        }
+
+        let id = TypeId::of::<T>();
+
+        let Some(registered) = self.registered.get(&id) else {
+            return values
+                .next()
+                .map_or(Ok(0), |value| Err(EventError::unregisted_event(value)));
+        };
+
+        Ok(self.handle_events(id, registered, values))
+    }
+
+    /// Stores a new event without calling the registered event listener.
+    /// Listeners get called later, when stored events are dispatched with [`EventBackend::dispatch_stored`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if
+    ///     - the type can not be used as an event
+    ///     - the event type is not registered
+    ///     - the event type is not registered to store events
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    ///
+    /// system.new_event_deferred::<u32>(42).unwrap();
+    /// # }
+    /// ```
+    pub fn new_event_deferred<T: 'static>(&self, value: T) -> Result<(), EventError<T, Value>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            let err = EventSizeError::new(EVENT_SIZE, Event::<EVENT_SIZE>::size_requirement::<T>());
+            return Err(EventError::event_size(value, err));
+        }
+
+        let id = TypeId::of::<T>();
+
+        let Some(registered) = self.registered.get(&id) else {
+            return Err(EventError::unregisted_event(value));
+        };
+
+        if !registered.has_store() {
+            return Err(EventError::registered_without_store_value(value));
+        }
+
+        if !self.is_paused() {
+            registered.store_event(Event::new(value));
+        }
+
+        Ok(())
+    }
+
+    /// Calls the registered event listeners for all stored events of every registered type.
+    /// Returns the number of dispatched events.
+    ///
+    /// Dispatched events are removed from their store. Events of disabled types stay stored,
+    /// while the `EventBackend` is paused, all events stay stored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// system.new_event_deferred::<u32>(42).unwrap();
+    ///
+    /// assert_eq!(system.dispatch_stored(), 1);
+    /// # }
+    /// ```
+    // dispatching calls listeners, the number of dispatched events is only informational
+    #[allow(clippy::must_use_candidate)]
+    pub fn dispatch_stored(&self) -> usize {
+        if self.is_paused() {
+            return 0;
+        }
+
+        self.registered
+            .iter()
+            .map(|(id, registered)| {
+                registered.dispatch_stored(&|payload| self.on_panic(*id, payload))
+            })
+            .sum()
+    }
+
+    /// Processes stored events of every registered type, until all events are processed or the time budget is used up.
+    /// Returns the number of processed events.
+    ///
+    /// Processed events are removed from their store. Events that could not be processed within the budget stay stored,
+    /// as if they were stored before any event triggered while processing. Size limited stores may drop them.
+    /// If `f` panics, the event it was called with is dropped, while all remaining events stay stored.
+    ///
+    /// Event types are processed one after another in registration order, so if the budget is used up,
+    /// event types registered later may not get processed at all.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// let processed = system.process_all_within(Duration::from_millis(2), |id, event| {
+    ///     // handle event
+    /// });
+    /// # }
+    /// ```
+    pub fn process_all_within(
+        &self,
+        budget: Duration,
+        mut f: impl FnMut(TypeId, &Event<EVENT_SIZE>),
+    ) -> usize {
+        let start = Instant::now();
+        let mut processed = 0;
+
+        for (id, registered) in self.registered.iter() {
+            // take stored events, so the lock is not held while processing
+            let Some(events) = registered.take_events() else {
+                continue;
+            };
+
+            // remaining events are put back, even if `f` panics
+            let mut pending = Requeue::new(registered, events);
+
+            while start.elapsed() < budget {
+                let Some(event) = pending.pop() else {
+                    break;
+                };
+
+                f(*id, &event);
+                processed += 1;
+            }
+
+            // budget is used up, remaining events are put back
+            if !pending.is_empty() {
+                break;
+            }
+        }
+
+        processed
+    }
+
+    /// Returns an iterator over each event with the matching event type.
+    ///
+    /// All stored events are taken out of the store, so each event is only returned by a single query.
+    /// Events that are not consumed get dropped together with the query.
+    /// To read stored events without consuming them, see [`EventBackend::peek`] and [`EventBackend::query_peek`].
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// let query = system.query::<u32>().unwrap();
+    ///
+    /// for event in query {
+    ///     // handle event
+    /// }
+    /// # }
+    /// ```
+    pub fn query<T: 'static>(&self) -> Result<UnblockingQuery<T, EVENT_SIZE>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.events_clone().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| Ok(UnblockingQuery::new(events)),
+                )
+            },
+        )
+    }
+
+    /// Returns an iterator over each event with the matching event type. See [`EventBackend::query`].
+    ///
+    /// Takes the stored buffer as a whole instead of swapping in a preallocated one,
+    /// so the slot only allocates again once new events are stored.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// let events = system.drain::<u32>().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(events, [42]);
+    /// # }
+    /// ```
+    pub fn drain<T: 'static>(&self) -> Result<UnblockingQuery<T, EVENT_SIZE>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.take_events().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| Ok(UnblockingQuery::new(events)),
+                )
+            },
+        )
+    }
+
+    /// Returns an iterator over each event with the matching event type together with its position. See [`EventBackend::query`].
+    ///
+    /// The position is the zero-based index within the queried batch, not a global sequence number.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// for (index, event) in system.query_indexed::<u32>().unwrap() {
+    ///     // correlate event with index
+    /// }
+    /// # }
+    /// ```
+    pub fn query_indexed<T: 'static>(
+        &self,
+    ) -> Result<impl Iterator<Item = (usize, T)>, EventError<T>> {
+        self.query::<T>().map(Iterator::enumerate)
+    }
+
+    /// Returns an iterator over each event with the matching event type.
+    ///
+    /// # Warning
+    /// Holding the query will block access to this event type, but will not move the underlying data. For a not-blocking query, that takes the events out of the store, see [`EventBackend::query`].
+    ///
+    /// Events that are not consumed get dropped together with the query.
+    /// If the thread panics while holding the query, all remaining events stay stored.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// let query = system.query_blocking::<u32>().unwrap();
+    ///
+    /// for event in query {
+    ///     // handle event
+    /// }
+    /// # }
+    /// ```
+    pub fn query_blocking<T: 'static>(&self) -> Result<Query<T, EVENT_SIZE>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.events().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| Ok(Query::new(events)),
+                )
+            },
+        )
+    }
+
+    /// Takes all stored events with the matching event type at once, leaving an empty store behind.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// let events = system.take_slot::<u32>().unwrap();
+    /// assert_eq!(events, [42]);
+    /// # }
+    /// ```
+    pub fn take_slot<T: 'static>(&self) -> Result<VecDeque<T>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.take_events().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| Ok(events.into_iter().map(Event::get).collect()),
+                )
+            },
+        )
+    }
+
+    /// Takes all stored events of a statically known set of event types.
+    ///
+    /// All event types are checked before any events are taken, so an error leaves all stores untouched.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if one of the given types was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if one of the given types is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// # system.register_store::<u64>(SlotType::All);
+    /// system.new_event::<u32>(1).unwrap();
+    /// system.new_event::<u64>(2).unwrap();
+    ///
+    /// let (a, b) = system.drain_set::<(u32, u64)>().unwrap();
+    /// assert_eq!(a, [1]);
+    /// assert_eq!(b, [2]);
+    /// # }
+    /// ```
+    pub fn drain_set<S: EventSet<EVENT_SIZE>>(&self) -> Result<S::Output, Box<dyn Error>> {
+        S::drain(self)
+    }
+
+    /// Takes all stored events of several event types and converts them into one common type.
+    ///
+    /// Events are appended type after type, in the order the converters were added to `merge`.
+    /// Events of the same type keep their order.
+    /// All event types are checked before any events are taken, so an error leaves all stores untouched.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if one of the merged types was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if one of the merged types is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, EventMerge, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// #[derive(Debug, PartialEq)]
+    /// enum Input {
+    ///     Key(char),
+    ///     Click(u32),
+    /// }
+    ///
+    /// system.register_store::<char>(SlotType::All).unwrap();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    ///
+    /// let merge = EventMerge::new()
+    ///     .with::<char>(Input::Key)
+    ///     .with::<u32>(Input::Click);
+    ///
+    /// system.new_event('a').unwrap();
+    /// system.new_event::<u32>(1).unwrap();
+    ///
+    /// let events = system.drain_mapped_all(&merge).unwrap();
+    /// assert_eq!(events, [Input::Key('a'), Input::Click(1)]);
+    /// # }
+    /// ```
+    pub fn drain_mapped_all<U: 'static>(
+        &self,
+        merge: &EventMerge<U, EVENT_SIZE>,
+    ) -> Result<Vec<U>, Box<dyn Error>> {
+        merge.drain(self)
+    }
+
+    /// Checks that events with the matching event type are stored.
+    pub(crate) fn check_store<T: 'static>(&self) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        match self.registered.get(&id) {
+            Some(registed) if registed.has_store() => Ok(()),
+            Some(_) => Err(EventError::registered_without_store()),
+            None => Err(EventError::unregisted_event_empty()),
+        }
+    }
+
+    /// Takes all stored events with the matching event type together with the number of their occurrences.
+    ///
+    /// Only stores registered with [`EventBackend::register_store_counting`] keep track of occurrences,
+    /// events from all other stores have a count of 1.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store_counting::<char>().unwrap();
+    ///
+    /// system.new_event('a').unwrap();
+    /// system.new_event('b').unwrap();
+    /// system.new_event('a').unwrap();
+    ///
+    /// let counts = system.query_counts::<char>().unwrap();
+    /// assert_eq!(counts, [('a', 2), ('b', 1)]);
+    /// # }
+    /// ```
+    pub fn query_counts<T: 'static>(&self) -> Result<Vec<(T, usize)>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.take_counted().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| {
+                        Ok(events
+                            .into_iter()
+                            .map(|(event, count)| (event.get(), count))
+                            .collect())
+                    },
+                )
+            },
+        )
+    }
+
+    /// Takes the value accumulated from all events with the matching event type, since it was last taken.
+    /// Returns `None`, if no event was folded since then, the type was not registered with
+    /// [`register_store_accumulate`](Self::register_store_accumulate), or `A` is not the accumulated type.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type was registered without a store.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store_accumulate::<u32, u64>(0, |sum, value| *sum += u64::from(*value)).unwrap();
+    ///
+    /// system.new_event(3u32).unwrap();
+    /// system.new_event(4u32).unwrap();
+    ///
+    /// assert_eq!(system.query_accumulated::<u32, u64>().unwrap(), Some(7));
+    /// assert_eq!(system.query_accumulated::<u32, u64>().unwrap(), None);
+    /// # }
+    /// ```
+    pub fn query_accumulated<T: 'static, A: 'static>(&self) -> Result<Option<A>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                if !registed.has_store() {
+                    return Err(EventError::registered_without_store());
+                }
+
+                Ok(registed.take_accumulated())
+            },
+        )
+    }
+
+    /// Takes all stored events with the matching event type into a shared slice, that can be cheaply cloned and passed to multiple consumers.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// let events = system.query_shared::<u32>().unwrap();
+    /// let shared = events.clone();
+    ///
+    /// assert_eq!(&*shared, &[42]);
+    /// # }
+    /// ```
+    pub fn query_shared<T: 'static>(&self) -> Result<Arc<[T]>, EventError<T>> {
+        let events = self.take_slot::<T>()?;
+
+        Ok(events.into_iter().collect())
+    }
+
+    /// Folds all stored events with the matching event type into a single value.
+    ///
+    /// All stored events are consumed, even if `f` panics.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(1).unwrap();
+    /// system.new_event::<u32>(2).unwrap();
+    ///
+    /// let sum = system.fold::<u32, u32>(0, |sum, event| sum + event).unwrap();
+    /// assert_eq!(sum, 3);
+    /// # }
+    /// ```
+    pub fn fold<T: 'static, A>(
+        &self,
+        init: A,
+        f: impl FnMut(A, T) -> A,
+    ) -> Result<A, EventError<T>> {
+        let events = self.take_slot::<T>()?;
+
+        Ok(events.into_iter().fold(init, f))
+    }
+
+    /// Processes all stored events with the matching event type and collects the returned errors.
+    ///
+    /// Events for which `f` returns an error are put back into the store in their original order,
+    /// as if they were stored before any event triggered while processing. Size limited stores may drop them.
+    /// They are not processed again within the same call.
+    /// If `f` panics, the event it was called with is dropped, while all failed and remaining events stay stored.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(1).unwrap();
+    /// system.new_event::<u32>(2).unwrap();
+    ///
+    /// let errors = system.process_retry::<u32, &str>(|event| {
+    ///     if *event == 2 { Err("not ready") } else { Ok(()) }
+    /// }).unwrap();
+    /// assert_eq!(errors, ["not ready"]);
+    ///
+    /// // 2 stays stored for the next attempt
+    /// assert_eq!(system.front::<u32>().unwrap(), Some(2));
+    /// # }
+    /// ```
+    pub fn process_retry<T: 'static, E>(
+        &self,
+        mut f: impl FnMut(&T) -> Result<(), E>,
+    ) -> Result<Vec<E>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        let Some(registed) = self.registered.get(&id) else {
+            return Err(EventError::unregisted_event_empty());
+        };
+
+        // take stored events, so the lock is not held while processing
+        let Some(events) = registed.take_events() else {
+            return Err(EventError::registered_without_store());
+        };
+
+        let taken = events.len();
+        let mut errors = Vec::new();
+
+        // failed and remaining events are put back, even if `f` panics
+        let mut pending = Requeue::new(registed, events);
+
+        for _ in 0..taken {
+            let Some(event) = pending.pop() else {
+                break;
+            };
+
+            if let Err(err) = f(event.get_ref::<T>()) {
+                pending.retry(event);
+                errors.push(err);
+            }
+        }
+
+        Ok(errors)
+    }
+
+    /// Returns a view over each event with the matching event type, without removing the events.
+    ///
+    /// # Warning
+    /// Holding the query will block access to this event type, like [`EventBackend::query_blocking`].
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// {
+    ///     let peek = system.query_peek::<u32>().unwrap();
+    ///     for event in &peek {
+    ///         // inspect event
+    ///     }
+    /// }
+    ///
+    /// // events are still stored
+    /// assert_eq!(system.query::<u32>().unwrap().len(), 1);
+    /// # }
+    /// ```
+    #[doc(alias = "peek_blocking")]
+    pub fn query_peek<T: 'static>(&self) -> Result<PeekQuery<'_, T, EVENT_SIZE>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.stored().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| Ok(PeekQuery::new(events)),
+                )
+            },
+        )
+    }
+
+    /// Returns clones of all stored events from a specific type, without consuming them.
+    ///
+    /// Unlike [`EventBackend::query_peek`], the stored events are only locked while they get cloned.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// assert_eq!(system.peek::<u32>().unwrap(), [42]);
+    ///
+    /// // events are still stored
+    /// assert_eq!(system.query::<u32>().unwrap().len(), 1);
+    /// # }
+    /// ```
+    pub fn peek<T: Clone + 'static>(&self) -> Result<Vec<T>, EventError<T>> {
+        let peek = self.query_peek::<T>()?;
+
+        Ok(peek.iter().cloned().collect())
+    }
+
+    /// Serializes all stored events from a specific type as a sequence, without consuming them.
+    ///
+    /// # Warning
+    /// The stored events are locked while they get serialized, like with [`EventBackend::query_peek`].
+    ///
+    /// # Errors
+    /// Returns the error of the serializer, if
+    ///     - the given type was not registered as event type, or is not registered to store events
+    ///     - serializing an event failed
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// let mut json = Vec::new();
+    /// system
+    ///     .serialize_pending::<u32, _>(&mut serde_json::Serializer::new(&mut json))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(json, b"[42]");
+    /// # }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn serialize_pending<T, S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: serde::Serialize + 'static,
+        S: serde::Serializer,
+    {
+        let peek = self
+            .query_peek::<T>()
+            .map_err(<S::Error as serde::ser::Error>::custom)?;
+
+        serializer.collect_seq(&peek)
+    }
+
+    /// Returns a clone of the oldest stored event with the matching event type, without removing it.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(1).unwrap();
+    /// system.new_event::<u32>(2).unwrap();
+    ///
+    /// assert_eq!(system.front::<u32>().unwrap(), Some(1));
+    /// # }
+    /// ```
+    pub fn front<T: Clone + 'static>(&self) -> Result<Option<T>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed.stored().map_or_else(
+                    || Err(EventError::registered_without_store()),
+                    |events| Ok(events.front().map(|event| event.get_ref::<T>().clone())),
+                )
+            },
+        )
+    }
+
+    /// Returns the number of events with the matching event type, that got dropped by its store since the last query.
+    ///
+    /// Events get dropped by stores, that don't keep every event, e.g. [`SlotType::Max`] when full or [`SlotType::AllFilter`].
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::Last).unwrap();
+    ///
+    /// system.new_event::<u32>(1).unwrap();
+    /// system.new_event::<u32>(2).unwrap();
+    ///
+    /// assert_eq!(system.dropped_count::<u32>().unwrap(), 1);
+    /// # }
+    /// ```
+    pub fn dropped_count<T: 'static>(&self) -> Result<u64, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed
+                    .dropped()
+                    .ok_or_else(EventError::registered_without_store)
+            },
+        )
+    }
+
+    /// Returns the number of stored events with the matching event type.
+    ///
+    /// The number is read without locking the store, so it is cheap to poll frequently.
+    /// Events stored or removed while the store is locked, e.g. by [`EventBackend::query_blocking`], are only counted once the lock is released.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(1).unwrap();
+    /// system.new_event::<u32>(2).unwrap();
+    ///
+    /// assert_eq!(system.len_for::<u32>().unwrap(), 2);
+    /// # }
+    /// ```
+    #[doc(alias("pending", "event_count"))]
+    pub fn len_for<T: 'static>(&self) -> Result<usize, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed
+                    .len()
+                    .ok_or_else(EventError::registered_without_store)
+            },
+        )
+    }
+
+    /// Returns the number of active listeners for the matching event type.
+    ///
+    /// Removed listeners, dropped subscriptions and one-shot listeners, that already got called, are not counted.
+    /// Like [`EventBackend::len_for`], this does not lock anything.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert_eq!(system.listener_count::<u32>().unwrap(), 1);
+    /// # }
+    /// ```
+    pub fn listener_count<T: 'static>(&self) -> Result<usize, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| Ok(registed.listener_count()),
+        )
+    }
+
+    /// Returns `true`, if the given type was registered as event type, either with a store or with a listener.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventBackend;
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_listener::<u32>(|event: &u32| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert!(system.is_registered::<u32>());
+    /// assert!(!system.is_registered::<u64>());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_registered<T: 'static>(&self) -> bool {
+        self.registered.get(&TypeId::of::<T>()).is_some()
+    }
+
+    /// Returns `true`, if the given type was registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.register_listener::<u64>(|event: &u64| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert!(system.is_store_registered::<u32>());
+    /// assert!(!system.is_store_registered::<u64>());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_store_registered<T: 'static>(&self) -> bool {
+        self.registered
+            .get(&TypeId::of::<T>())
+            .is_some_and(Registered::has_store)
+    }
+
+    /// Returns the number of registered event types.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.register_listener::<u64>(|event: &u64| {
+    ///     // handle event
+    /// }).unwrap();
+    ///
+    /// assert_eq!(system.registered_type_count(), 2);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn registered_type_count(&self) -> usize {
+        self.registered.len()
+    }
+
+    /// Returns the ids of all registered event types, in registration order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::any::TypeId;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.register_store::<u64>(SlotType::All).unwrap();
+    ///
+    /// let types = system.registered_types().collect::<Vec<_>>();
+    /// assert_eq!(types, [TypeId::of::<u32>(), TypeId::of::<u64>()]);
+    /// # }
+    /// ```
+    pub fn registered_types(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.registered.iter().map(|(id, _)| *id)
+    }
+
+    /// Returns the ids of all event types, that have stored events.
+    ///
+    /// Like [`EventBackend::len_for`], this does not lock any store.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::any::TypeId;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.register_store::<u64>(SlotType::All).unwrap();
+    ///
+    /// system.new_event::<u32>(1).unwrap();
+    ///
+    /// assert_eq!(system.pending_types(), [TypeId::of::<u32>()]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn pending_types(&self) -> Vec<TypeId> {
+        self.registered
+            .iter()
+            .filter(|(_, registered)| registered.len().is_some_and(|len| len > 0))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Returns a snapshot of the state of all registered event types, e.g. for a debug overlay or a log line.
+    ///
+    /// Like [`EventBackend::len_for`], this does not lock any store.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::any::TypeId;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::Max(8)).unwrap();
+    /// system.new_event::<u32>(1).unwrap();
+    ///
+    /// let diagnostics = system.diagnostics();
+    /// let typ = diagnostics.get(TypeId::of::<u32>()).unwrap();
+    ///
+    /// assert_eq!(typ.len, Some(1));
+    /// assert_eq!(typ.capacity, Some(8));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn diagnostics(&self) -> Diagnostics {
+        let types = self
+            .registered
+            .iter()
+            .map(|(id, registered)| registered.diagnostics(*id))
+            .collect();
+
+        Diagnostics { types }
+    }
+
+    /// Returns a snapshot of the state of the event type with the matching id, or `None` if the type is not registered.
+    /// See [`EventBackend::diagnostics`].
+    ///
+    /// Useful together with [`EventBackend::registered_types`], when the event type is not known at compile time.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.new_event::<u32>(1).unwrap();
+    ///
+    /// for id in system.registered_types() {
+    ///     let typ = system.type_info(id).unwrap();
+    ///     assert_eq!(typ.len, Some(1));
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn type_info(&self, id: TypeId) -> Option<TypeDiagnostics> {
+        self.registered
+            .get(&id)
+            .map(|registered| registered.diagnostics(id))
+    }
+
+    /// Removes all stored events with the matching event type, keeping its listeners and store configuration.
+    /// Returns the number of removed events.
+    ///
+    /// Unlike [`EventBackend::reset`], additional state of the store and the number of dropped events are kept.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// assert_eq!(system.clear::<u32>().unwrap(), 1);
+    /// assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    /// # }
+    /// ```
+    #[doc(alias = "clear_events")]
+    pub fn clear<T: 'static>(&self) -> Result<usize, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed
+                    .clear()
+                    .ok_or_else(EventError::registered_without_store)
+            },
+        )
+    }
+
+    /// Removes all stored events of every event type, keeping listeners and store configurations.
+    /// Returns the number of removed events for every event type registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::any::TypeId;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// let cleared = system.clear_all();
+    /// assert_eq!(cleared[&TypeId::of::<u32>()], 1);
+    /// # }
+    /// ```
+    #[doc(alias = "clear_all_events")]
+    // clearing removes events, the number of removed events is only informational
+    #[allow(clippy::must_use_candidate)]
+    pub fn clear_all(&self) -> HashMap<TypeId, usize> {
+        self.registered
+            .iter()
+            .filter_map(|(id, registered)| registered.clear().map(|cleared| (*id, cleared)))
+            .collect()
+    }
+
+    /// Reserves capacity for at least `additional` more events with the matching event type,
+    /// so a burst of events can be stored without reallocating.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.reserve::<u32>(1024).unwrap();
+    /// # }
+    /// ```
+    pub fn reserve<T: 'static>(&self, additional: usize) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                registed
+                    .reserve(additional)
+                    .ok_or_else(EventError::registered_without_store)
+            },
+        )
+    }
+
+    /// Removes all stored events with the matching event type and resets the store to its initial state.
+    ///
+    /// Next to stored events, this also resets additional state of the store, e.g. counts of a counting store,
+    /// as well as the number of dropped events.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the given type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// system.reset::<u32>().unwrap();
+    /// # }
+    /// ```
+    pub fn reset<T: 'static>(&self) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        match self.registered.get(&id) {
+            Some(registered) if registered.has_store() => registered.reset(),
+            Some(_) => return Err(EventError::registered_without_store()),
+            None => return Err(EventError::unregisted_event_empty()),
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over each event with the matching event type. See [`EventBackend::query_blocking`].
+    ///
+    /// Waits at most `timeout` for access to this event type. Returns `None`, if the timeout was reached.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    /// Returns an `RegisteredWithoutStore` error, if the queried type is not registered to store events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// let query = system.query_blocking_timeout::<u32>(Duration::from_millis(1)).unwrap();
+    /// if let Some(query) = query {
+    ///     for event in query {
+    ///         // handle event
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn query_blocking_timeout<T: 'static>(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Query<'_, T, EVENT_SIZE>>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registed| {
+                if !registed.has_store() {
+                    return Err(EventError::registered_without_store());
+                }
+
+                Ok(registed.events_timeout(timeout).map(Query::new))
+            },
+        )
+    }
+
+    /// Disables specific event from being processed.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventTypeError`, if the event type is not registered or no event listener was registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u64>(SlotType::All);
+    /// system.disable::<u64>().unwrap();
+    /// # }
+    /// ```
+    pub fn disable<T: 'static>(&self) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        match self.registered.get(&id) {
+            Some(registered) => registered.disable(),
+            None => return Err(EventError::unregisted_event_empty()),
+        }
+
+        Ok(())
+    }
+
+    /// Disables specific event until the returned guard is dropped.
+    ///
+    /// Dropping the guard enables the event again, even if it was already disabled before.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventTypeError`, if the event type is not registered or no event listener was registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u64>(SlotType::Last);
+    /// {
+    ///     let _guard = system.scoped_disable::<u64>().unwrap();
+    ///     assert!(!system.is_enabled::<u64>().unwrap());
+    /// }
+    ///
+    /// assert!(system.is_enabled::<u64>().unwrap());
+    /// # }
+    /// ```
+    pub fn scoped_disable<T: 'static>(&self) -> Result<DisableGuard<'_, T>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registered| Ok(DisableGuard::new(registered.enabled_flag())),
+        )
+    }
+
+    /// Disables all events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.disable_all();
+    /// # }
+    /// ```
+    pub fn disable_all(&self) {
+        for registered in self.registered.values() {
+            registered.disable();
+        }
+    }
+
+    /// Pauses processing of all events, without changing whether specific events are enabled.
+    ///
+    /// While paused, new events are still checked, but neither passed to listeners nor stored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u32>(SlotType::All);
+    /// system.pause();
+    /// system.new_event::<u32>(42).unwrap();
+    /// system.resume();
+    ///
+    /// assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    /// # }
+    /// ```
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    /// Resumes processing of events, after the `EventBackend` was paused.
+    /// Events that were disabled before stay disabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.pause();
+    /// system.resume();
+    /// # }
+    /// ```
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true`, if processing of all events is paused.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.pause();
+    /// assert!(system.is_paused());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Enables specific event for processesing.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventTypeError`, if the event type is not registered or no event listener was registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u64>(SlotType::Last);
+    /// system.enable::<u64>().unwrap();
+    /// # }
+    /// ```
+    pub fn enable<T: 'static>(&self) -> Result<(), EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        match self.registered.get(&id) {
+            Some(registered) => registered.enable(),
+            None => return Err(EventError::unregisted_event_empty()),
+        }
+
+        Ok(())
+    }
+
+    /// Enables all events.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.enable_all();
+    /// # }
+    /// ```
+    pub fn enable_all(&self) {
+        for registered in self.registered.values() {
+            registered.enable();
+        }
+    }
+
+    /// Returns `true`, if the specific event is enabled for processing.
+    ///
+    /// Pausing the `EventBackend` with [`EventBackend::pause`] does not change whether specific events are enabled.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventTypeError`, if the event type is not registered or no event listener was registered.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// # system.register_store::<u64>(SlotType::Last);
+    /// system.disable::<u64>().unwrap();
+    /// assert!(!system.is_enabled::<u64>().unwrap());
+    /// # }
+    /// ```
+    pub fn is_enabled<T: 'static>(&self) -> Result<bool, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        self.registered.get(&id).map_or_else(
+            || Err(EventError::unregisted_event_empty()),
+            |registered| Ok(registered.is_enabled()),
+        )
+    }
+
+    /// Frees allocated memory for batch events.
+    ///
+    /// # Warn
+    /// All events that are not consumed will get dropped.
+    pub fn cleanup(&mut self) {
+        for registered in self.registered.values_mut() {
+            registered.cleanup();
+        }
+    }
+
+    /// Removes the event type with all its listeners and stored events.
+    /// Returns the events, that were still stored. Event types without a store return no events.
+    ///
+    /// Afterwards, the event type is treated like it was never registered.
+    ///
+    /// # Errors
+    /// Returns an `UnregisteredEventType` error, if the given type was not registered as event type.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, SlotType};
+    /// # fn main() {
+    /// # let mut system = EventBackend::default();
+    /// system.register_store::<u32>(SlotType::All).unwrap();
+    /// system.new_event::<u32>(42).unwrap();
+    ///
+    /// assert_eq!(system.unregister::<u32>().unwrap(), [42]);
+    /// assert!(system.new_event::<u32>(1).is_err());
+    /// # }
+    /// ```
+    #[doc(alias = "deregister")]
+    pub fn unregister<T: 'static>(&mut self) -> Result<VecDeque<T>, EventError<T>> {
+        // check if T can be used as an event
+        if !Event::<EVENT_SIZE>::fitting::<T>() {
+            return Err(EventError::event_size_empty(EventSizeError::new(
+                EVENT_SIZE,
+                Event::<EVENT_SIZE>::size_requirement::<T>(),
+            )));
+        }
+
+        let id = TypeId::of::<T>();
+
+        let Some(registered) = self.registered.remove(&id) else {
+            return Err(EventError::unregisted_event_empty());
+        };
+
+        Ok(registered
+            .take_events()
+            .map_or_else(VecDeque::new, |events| {
+                events.into_iter().map(Event::get).collect()
+            }))
+    }
+}
+
+impl<const EVENT_SIZE: usize> Drop for EventBackend<EVENT_SIZE> {
+    fn drop(&mut self) {
+        for registered in self.registered.values_mut() {
+            registered.flush();
+        }
+    }
+}
 
 impl Default for EventBackend<DEFAULT_EVENT_SIZE> {
     #[inline]
@@ -364,92 +3072,558 @@ impl Default for EventBackend<DEFAULT_EVENT_SIZE> {
 
 impl<const EVENT_SIZE: usize> std::fmt::Debug for EventBackend<EVENT_SIZE> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("EventBackend")
-            .field("registered", &self.registered.len())
-            .finish()
+        let mut debug = f.debug_struct("EventBackend");
+        debug.field("registered", &self.registered.len());
+        debug.field("global", &self.global.len());
+        debug.field("panic_hook", &self.panic_hook.is_some());
+        debug.field("paused", &self.is_paused());
+
+        #[cfg(feature = "async")]
+        debug.field("tasks", &self.tasks);
+
+        debug.finish()
     }
 }
 
 type Listener<const SIZE: usize> = Box<dyn Fn(&Event<SIZE>) + Sync + RefUnwindSafe + Send>;
 
+/// Listener that can consume the event, so the following listeners don't get called.
+type FilteringListener<const SIZE: usize> =
+    Box<dyn Fn(&Event<SIZE>) -> bool + Sync + RefUnwindSafe + Send>;
+
+type PanicHook = Box<dyn Fn(TypeId, Box<dyn Any + Send>) + Sync + Send>;
+
+/// Receives the payload of a panicking listener.
+type OnPanic<'a> = &'a dyn Fn(Box<dyn Any + Send>);
+
+type GlobalListener<const SIZE: usize> =
+    Box<dyn Fn(TypeId, &Event<SIZE>) + Sync + RefUnwindSafe + Send>;
+
+type Flush<const SIZE: usize> = Box<dyn FnOnce(VecDeque<Event<SIZE>>) + Sync + Send>;
+
+struct ListenerEntry<const SIZE: usize> {
+    id: u64,
+    /// Flag of a subscription, the listener gets skipped once it is unset.
+    active: Option<Arc<AtomicBool>>,
+    /// Listeners with higher priority get called first.
+    priority: i32,
+    listener: EntryListener<SIZE>,
+}
+
+enum EntryListener<const SIZE: usize> {
+    Call(Listener<SIZE>),
+    Filtering(FilteringListener<SIZE>),
+}
+
+impl<const SIZE: usize> ListenerEntry<SIZE> {
+    #[inline]
+    fn is_active(&self) -> bool {
+        self.active
+            .as_ref()
+            .is_none_or(|active| active.load(Ordering::Acquire))
+    }
+}
+
 pub struct Registered<const SIZE: usize> {
+    type_name: &'static str,
     slot: Option<Slot<SIZE>>,
-    listener: Vec<Listener<SIZE>>,
+    listener: Vec<ListenerEntry<SIZE>>,
+    /// Id of the next registered listener.
+    next_listener: u64,
+    /// One-shot listeners, together with a flag if they got called or cancelled.
+    once: Vec<(Arc<AtomicBool>, Listener<SIZE>)>,
+    /// Names of named listeners, together with their id in `listener`.
+    named: Vec<(String, u64)>,
     enabled: AtomicBool,
+    dropped: AtomicU64,
+    flush: Option<Flush<SIZE>>,
 }
 
 impl<const SIZE: usize> Registered<SIZE> {
     #[inline]
-    pub fn new() -> Self {
+    pub fn new(type_name: &'static str) -> Self {
         Self {
+            type_name,
             slot: None,
             listener: Vec::new(),
+            next_listener: 0,
+            once: Vec::new(),
+            named: Vec::new(),
             enabled: AtomicBool::new(true),
+            dropped: AtomicU64::new(0),
+            flush: None,
+        }
+    }
+
+    pub fn handle_event(&self, event: Event<SIZE>, on_panic: OnPanic<'_>) -> EventOutcome {
+        // check if events for this registered type should be processed
+        if !self.enabled.load(Ordering::Relaxed) {
+            return EventOutcome::default();
+        }
+
+        let listeners_invoked = self.call_listeners(&event, on_panic);
+
+        // store event for querying it later
+        let pushed = self.push(event);
+
+        EventOutcome {
+            listeners_invoked,
+            listeners_panicked: 0,
+            stored: pushed.is_some_and(|pushed| pushed.stored),
+            dropped_by_slot: pushed.is_some_and(|pushed| !pushed.stored),
+        }
+    }
+
+    /// Stores all events at once, if this type is registered to store events.
+    #[inline]
+    fn push_all(&self, events: Vec<Event<SIZE>>) {
+        let Some(slot) = &self.slot else {
+            return;
+        };
+
+        let dropped = slot.push_all(events);
+
+        if dropped > 0 {
+            self.dropped.fetch_add(dropped as u64, Ordering::Relaxed);
         }
     }
 
-    pub fn handle_event(&self, event: Event<SIZE>) {
+    pub fn store_event(&self, event: Event<SIZE>) {
         // check if events for this registered type should be processed
-        if !self.enabled.load(std::sync::atomic::Ordering::Relaxed) {
+        if !self.enabled.load(Ordering::Relaxed) {
             return;
         }
 
-        // call all listeners
-        for listener in &self.listener {
-            _ = std::panic::catch_unwind(|| (listener)(&event));
+        _ = self.push(event);
+    }
+
+    /// Stores the event, if this type is registered to store events.
+    #[inline]
+    fn push(&self, event: Event<SIZE>) -> Option<Pushed> {
+        let pushed = self.slot.as_ref()?.push(event);
+
+        if pushed.dropped {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
         }
 
-        // store event for querying it later
-        if let Some(slot) = &self.slot {
-            slot.push(event);
+        Some(pushed)
+    }
+
+    pub fn dispatch_stored(&self, on_panic: OnPanic<'_>) -> usize {
+        // check if events for this registered type should be processed
+        if !self.enabled.load(Ordering::Relaxed) {
+            return 0;
+        }
+
+        // take stored events, so the lock is not held while listeners are running
+        let Some(events) = self.take_events() else {
+            return 0;
+        };
+
+        for event in &events {
+            _ = self.call_listeners(event, on_panic);
         }
+
+        events.len()
+    }
+
+    /// Calls all listeners with the event. Returns the number of called listeners.
+    /// Panics of listeners are caught and passed to `on_panic`.
+    #[inline]
+    fn call_listeners(&self, event: &Event<SIZE>, on_panic: OnPanic<'_>) -> usize {
+        let mut called = 0;
+
+        for entry in &self.listener {
+            if !entry.is_active() {
+                continue;
+            }
+
+            let result = match &entry.listener {
+                EntryListener::Call(listener) => {
+                    std::panic::catch_unwind(|| listener(event)).map(|()| false)
+                }
+                EntryListener::Filtering(listener) => std::panic::catch_unwind(|| listener(event)),
+            };
+
+            // a panicking listener does not consume the event
+            let consumed = result.unwrap_or_else(|payload| {
+                on_panic(payload);
+                false
+            });
+            called += 1;
+
+            // event got consumed, skip all remaining listeners, one-shot listeners still get called
+            if consumed {
+                break;
+            }
+        }
+
+        for (done, listener) in &self.once {
+            // only the first caller gets to call the listener
+            if done.swap(true, Ordering::AcqRel) {
+                continue;
+            }
+
+            if let Err(payload) = std::panic::catch_unwind(|| (listener)(event)) {
+                on_panic(payload);
+            }
+            called += 1;
+        }
+
+        called
+    }
+
+    /// Adds a one-shot listener and removes all one-shot listeners, that got called or cancelled.
+    #[inline]
+    pub fn push_once(&mut self, done: Arc<AtomicBool>, listener: Listener<SIZE>) {
+        self.once.retain(|(done, _)| !done.load(Ordering::Acquire));
+        self.once.push((done, listener));
+    }
+
+    #[inline]
+    pub const fn has_store(&self) -> bool {
+        self.slot.is_some()
     }
 
     #[inline]
     pub fn events_clone(&self) -> Option<VecDeque<Event<SIZE>>> {
-        self.slot.as_ref().map(Slot::events_clone)
+        let slot = self.slot.as_ref()?;
+        Some(slot.events_clone_locked(&mut self.events_reset(slot)))
+    }
+
+    #[inline]
+    pub fn events(&self) -> Option<SlotGuard<'_, SIZE>> {
+        let slot = self.slot.as_ref()?;
+        Some(self.events_reset(slot))
+    }
+
+    /// Locks the stored events, then resets the number of dropped events while holding the lock.
+    #[inline]
+    fn events_reset<'a>(&self, slot: &'a Slot<SIZE>) -> SlotGuard<'a, SIZE> {
+        let events = slot.events();
+        self.reset_dropped();
+
+        events
+    }
+
+    /// Tries to lock stored events until the timeout is reached.
+    /// Returns `None`, if the timeout was reached or no events are stored.
+    #[inline]
+    pub fn events_timeout(&self, timeout: Duration) -> Option<SlotGuard<'_, SIZE>> {
+        let events = self.slot.as_ref()?.events_timeout(timeout)?;
+        self.reset_dropped();
+
+        Some(events)
     }
 
+    /// Locks stored events without consuming them.
     #[inline]
-    pub fn events(&self) -> Option<MutexGuard<VecDeque<Event<SIZE>>>> {
+    pub fn stored(&self) -> Option<SlotGuard<'_, SIZE>> {
         self.slot.as_ref().map(Slot::events)
     }
 
+    #[inline]
+    pub fn take_events(&self) -> Option<VecDeque<Event<SIZE>>> {
+        let slot = self.slot.as_ref()?;
+        Some(std::mem::take(&mut *self.events_reset(slot)))
+    }
+
+    #[inline]
+    pub fn take_counted(&self) -> Option<Vec<(Event<SIZE>, usize)>> {
+        let slot = self.slot.as_ref()?;
+        Some(slot.take_counted_locked(&mut self.events_reset(slot)))
+    }
+
+    #[inline]
+    pub fn take_accumulated<A: 'static>(&self) -> Option<A> {
+        self.slot.as_ref().and_then(Slot::take_accumulated)
+    }
+
+    #[inline]
+    pub fn clear(&self) -> Option<usize> {
+        self.slot.as_ref().map(Slot::clear)
+    }
+
+    #[inline]
+    pub fn reserve(&self, additional: usize) -> Option<()> {
+        self.slot.as_ref().map(|slot| slot.reserve(additional))
+    }
+
+    #[inline]
+    pub fn reset(&self) {
+        if let Some(slot) = &self.slot {
+            slot.reset_locked(&mut self.events_reset(slot));
+        }
+    }
+
+    /// Returns the number of stored events, without locking the slot.
+    #[inline]
+    pub fn len(&self) -> Option<usize> {
+        self.slot.as_ref().map(Slot::len)
+    }
+
+    #[inline]
+    pub fn diagnostics(&self, type_id: TypeId) -> TypeDiagnostics {
+        TypeDiagnostics {
+            type_id,
+            type_name: self.type_name,
+            slot: self.slot.as_ref().map(Slot::kind_name),
+            len: self.len(),
+            capacity: self.slot.as_ref().and_then(Slot::capacity),
+            enabled: self.enabled.load(Ordering::Relaxed),
+            listeners: self.listener_count(),
+            dropped: self.dropped(),
+        }
+    }
+
+    /// Returns the number of events dropped by the slot since the last query.
+    #[inline]
+    pub fn dropped(&self) -> Option<u64> {
+        self.slot
+            .as_ref()
+            .map(|_| self.dropped.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    fn reset_dropped(&self) {
+        self.dropped.store(0, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn requeue_front(&self, events: VecDeque<Event<SIZE>>) {
+        let Some(slot) = &self.slot else {
+            return;
+        };
+
+        let dropped = slot.requeue_front(events);
+
+        if dropped > 0 {
+            self.dropped.fetch_add(dropped as u64, Ordering::Relaxed);
+        }
+    }
+
+    /// Sets the listener registered under `name`. Returns `true`, if a listener got replaced.
+    #[inline]
+    pub fn set_named_listener(&mut self, name: &str, listener: Listener<SIZE>) -> bool {
+        let named = self.named.iter().find(|(named, _)| named == name);
+
+        if let Some((_, id)) = named {
+            if let Some(current) = self.listener.iter_mut().find(|entry| entry.id == *id) {
+                current.listener = EntryListener::Call(listener);
+                return true;
+            }
+        }
+
+        let id = self.push_listener(listener);
+        self.named.retain(|(named, _)| named != name);
+        self.named.push((name.to_owned(), id));
+
+        false
+    }
+
+    /// Adds a listener. Returns the id of the listener.
+    #[inline]
+    pub fn push_listener(&mut self, listener: Listener<SIZE>) -> u64 {
+        self.push_entry(EntryListener::Call(listener), None, 0)
+    }
+
+    /// Adds a listener, that can stop the following listeners from getting called. Returns the id of the listener.
+    #[inline]
+    pub fn push_filtering(&mut self, listener: FilteringListener<SIZE>, priority: i32) -> u64 {
+        self.push_entry(EntryListener::Filtering(listener), None, priority)
+    }
+
+    /// Adds a listener, that gets called before all listeners with a lower priority. Returns the id of the listener.
+    #[inline]
+    pub fn push_listener_with_priority(&mut self, listener: Listener<SIZE>, priority: i32) -> u64 {
+        self.push_entry(EntryListener::Call(listener), None, priority)
+    }
+
+    /// Adds a listener, that is skipped once `active` is unset. Returns the id of the listener.
+    #[inline]
+    pub fn push_subscription(&mut self, listener: Listener<SIZE>, active: Arc<AtomicBool>) -> u64 {
+        self.push_entry(EntryListener::Call(listener), Some(active), 0)
+    }
+
+    #[inline]
+    fn push_entry(
+        &mut self,
+        listener: EntryListener<SIZE>,
+        active: Option<Arc<AtomicBool>>,
+        priority: i32,
+    ) -> u64 {
+        // remove listeners of dropped subscriptions
+        self.listener.retain(ListenerEntry::is_active);
+
+        let id = self.next_listener;
+        self.next_listener += 1;
+
+        // keep listeners sorted by descending priority, listeners with the same priority stay in registration order
+        let index = self
+            .listener
+            .partition_point(|entry| entry.priority >= priority);
+
+        self.listener.insert(
+            index,
+            ListenerEntry {
+                id,
+                active,
+                priority,
+                listener,
+            },
+        );
+
+        id
+    }
+
+    /// Returns the number of active listeners, including one-shot listeners, that did not get called yet.
+    #[inline]
+    pub fn listener_count(&self) -> usize {
+        let listener = self
+            .listener
+            .iter()
+            .filter(|entry| entry.is_active())
+            .count();
+
+        let once = self
+            .once
+            .iter()
+            .filter(|(done, _)| !done.load(Ordering::Acquire))
+            .count();
+
+        listener + once
+    }
+
+    /// Removes the listener with the matching id. Returns `true`, if the listener got removed.
+    #[inline]
+    pub fn remove_listener(&mut self, id: u64) -> bool {
+        let Some(index) = self.listener.iter().position(|entry| entry.id == id) else {
+            return false;
+        };
+
+        _ = self.listener.remove(index);
+        self.named.retain(|(_, named)| *named != id);
+
+        true
+    }
+
     #[inline]
     pub fn cleanup(&mut self) {
         self.listener = Vec::new();
+        self.once = Vec::new();
+        self.named = Vec::new();
 
         if let Some(slot) = &mut self.slot {
             slot.cleanup();
         }
     }
 
+    /// Passes all stored events to the flush handler, if one is set.
+    #[inline]
+    pub fn flush(&mut self) {
+        let Some(flush) = self.flush.take() else {
+            return;
+        };
+
+        if let Some(events) = self.take_events() {
+            _ = std::panic::catch_unwind(AssertUnwindSafe(|| flush(events)));
+        }
+    }
+
+    #[inline]
+    fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
     #[inline]
     fn enable(&self) {
-        self.enabled
-            .store(true, std::sync::atomic::Ordering::Relaxed);
+        self.enabled.store(true, Ordering::Relaxed);
     }
 
     #[inline]
     fn disable(&self) {
-        self.enabled
-            .store(false, std::sync::atomic::Ordering::Relaxed);
+        self.enabled.store(false, Ordering::Relaxed);
+    }
+
+    #[inline]
+    const fn enabled_flag(&self) -> &AtomicBool {
+        &self.enabled
     }
 }
 
 impl<const SIZE: usize> std::fmt::Debug for Registered<SIZE> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Registered")
+            .field("type_name", &self.type_name)
             .field("slot", &self.slot)
             .field("listener", &self.listener.len())
+            .field("next_listener", &self.next_listener)
+            .field("once", &self.once.len())
+            .field("named", &self.named)
             .field("enabled", &self.enabled)
+            .field("dropped", &self.dropped)
+            .field("flush", &self.flush.is_some())
             .finish()
     }
 }
 
+/// Events taken out of a store for processing, the remaining events are put back into the store when dropped.
+///
+/// Events are also put back, if processing panics, so a panicking callback does not discard the taken events.
+struct Requeue<'a, const SIZE: usize> {
+    registered: &'a Registered<SIZE>,
+    events: VecDeque<Event<SIZE>>,
+    /// Number of events at the back of `events`, that were taken before the events at the front.
+    retried: usize,
+}
+
+impl<'a, const SIZE: usize> Requeue<'a, SIZE> {
+    #[inline]
+    const fn new(registered: &'a Registered<SIZE>, events: VecDeque<Event<SIZE>>) -> Self {
+        Self {
+            registered,
+            events,
+            retried: 0,
+        }
+    }
+
+    /// Takes the next event to process.
+    #[inline]
+    fn pop(&mut self) -> Option<Event<SIZE>> {
+        self.events.pop_front()
+    }
+
+    /// Puts a taken event back, it is put back into the store before all events that were not taken yet.
+    #[inline]
+    fn retry(&mut self, event: Event<SIZE>) {
+        self.events.push_back(event);
+        self.retried += 1;
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl<const SIZE: usize> Drop for Requeue<'_, SIZE> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.events.is_empty() {
+            return;
+        }
+
+        // retried events were taken first, so they go in front of the remaining events
+        let mut events = std::mem::take(&mut self.events);
+        events.rotate_right(self.retried);
+
+        self.registered.requeue_front(events);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::DEFAULT_EVENT_SIZE;
+    use crate::{err::RawErr, DEFAULT_EVENT_SIZE};
 
     use super::EventBackend;
 
@@ -498,4 +3672,34 @@ mod tests {
 
         assert!(res.is_ok());
     }
+
+    #[test]
+    fn test_eventbackend_unregister() {
+        let mut events: EventBackend<DEFAULT_EVENT_SIZE> = EventBackend::new();
+
+        events.register_store::<u32>(crate::SlotType::All).unwrap();
+        events.register_store::<u64>(crate::SlotType::All).unwrap();
+        events.new_event::<u32>(1).unwrap();
+
+        assert_eq!(events.unregister::<u32>().unwrap(), [1]);
+        assert!(matches!(
+            events.unregister::<u32>().unwrap_err().raw_err(),
+            RawErr::UnregisteredEventType(_)
+        ));
+
+        assert!(matches!(
+            events.query::<u32>().unwrap_err().raw_err(),
+            RawErr::UnregisteredEventType(_)
+        ));
+        assert!(events.new_event::<u32>(1).is_err());
+
+        // other types keep working
+        events.new_event::<u64>(2).unwrap();
+        assert_eq!(events.query::<u64>().unwrap().collect::<Vec<_>>(), [2]);
+
+        // the type can be registered again
+        events.register_store::<u32>(crate::SlotType::All).unwrap();
+        events.new_event::<u32>(3).unwrap();
+        assert_eq!(events.query::<u32>().unwrap().collect::<Vec<_>>(), [3]);
+    }
 }
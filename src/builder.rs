@@ -0,0 +1,98 @@
+use std::{error::Error, panic::RefUnwindSafe};
+
+use crate::{EventBackend, SlotType, DEFAULT_EVENT_SIZE};
+
+/// Builds an [`EventBackend`] from a chain of registrations.
+///
+/// Registrations are applied in order. The first failing registration is kept and returned by
+/// [`EventBackendBuilder::build`], all registrations after it are skipped.
+///
+/// # Example
+/// ```rust
+/// # use eventsys::{EventBackendBuilder, SlotType};
+/// # fn main() {
+/// let system = EventBackendBuilder::default()
+///     .store::<u32>(SlotType::All)
+///     .listener::<u64>(|event: &u64| {
+///         // handle event
+///     })
+///     .build()
+///     .unwrap();
+/// # }
+/// ```
+pub struct EventBackendBuilder<const EVENT_SIZE: usize = DEFAULT_EVENT_SIZE> {
+    backend: EventBackend<EVENT_SIZE>,
+    error: Option<Box<dyn Error>>,
+}
+
+impl<const EVENT_SIZE: usize> EventBackendBuilder<EVENT_SIZE> {
+    /// Creates a new `EventBackendBuilder` without any registrations.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            backend: EventBackend::new(),
+            error: None,
+        }
+    }
+
+    /// Registers a new type of event. See [`EventBackend::register_store`].
+    #[must_use]
+    pub fn store<T: 'static>(self, typ: SlotType<T>) -> Self {
+        self.register(|backend| backend.register_store(typ).map_err(Into::into))
+    }
+
+    /// Registers a function that gets called, if an event with the matching type is triggered.
+    /// See [`EventBackend::register_listener`].
+    #[must_use]
+    pub fn listener<T: 'static>(
+        self,
+        listener: impl Fn(&T) + Send + Sync + RefUnwindSafe + 'static,
+    ) -> Self {
+        self.register(|backend| {
+            backend
+                .register_listener(listener)
+                .map(|_| ())
+                .map_err(Into::into)
+        })
+    }
+
+    /// Returns the built `EventBackend`.
+    ///
+    /// # Errors
+    /// Returns the error of the first failing registration.
+    pub fn build(self) -> Result<EventBackend<EVENT_SIZE>, Box<dyn Error>> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.backend),
+        }
+    }
+
+    /// Applies a registration, unless a previous registration failed.
+    #[inline]
+    fn register(
+        mut self,
+        register: impl FnOnce(&mut EventBackend<EVENT_SIZE>) -> Result<(), Box<dyn Error>>,
+    ) -> Self {
+        if self.error.is_none() {
+            self.error = register(&mut self.backend).err();
+        }
+
+        self
+    }
+}
+
+impl Default for EventBackendBuilder<DEFAULT_EVENT_SIZE> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const EVENT_SIZE: usize> std::fmt::Debug for EventBackendBuilder<EVENT_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBackendBuilder")
+            .field("backend", &self.backend)
+            .field("error", &self.error)
+            .finish()
+    }
+}
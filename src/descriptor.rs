@@ -0,0 +1,50 @@
+use std::error::Error;
+
+use crate::{EventBackend, DEFAULT_EVENT_SIZE};
+
+type Register<const EVENT_SIZE: usize> =
+    Box<dyn Fn(&mut EventBackend<EVENT_SIZE>) -> Result<(), Box<dyn Error>>>;
+
+/// Describes the registration of event types and event listeners.
+///
+/// Descriptors allow modules to export their registrations as data, which then gets applied to an [`EventBackend`]
+/// with [`EventBackend::bulk_register`].
+pub struct EventDescriptor<const EVENT_SIZE: usize = DEFAULT_EVENT_SIZE> {
+    register: Register<EVENT_SIZE>,
+}
+
+impl<const EVENT_SIZE: usize> EventDescriptor<EVENT_SIZE> {
+    /// Creates a new `EventDescriptor` from a registration function.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::{EventBackend, EventDescriptor, SlotType};
+    /// # fn main() {
+    /// let descriptor = EventDescriptor::new(|system: &mut EventBackend| {
+    ///     system.register_store::<u32>(SlotType::All)?;
+    ///     Ok(())
+    /// });
+    /// # }
+    /// ```
+    pub fn new(
+        register: impl Fn(&mut EventBackend<EVENT_SIZE>) -> Result<(), Box<dyn Error>> + 'static,
+    ) -> Self {
+        Self {
+            register: Box::new(register),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn register(
+        &self,
+        backend: &mut EventBackend<EVENT_SIZE>,
+    ) -> Result<(), Box<dyn Error>> {
+        (self.register)(backend)
+    }
+}
+
+impl<const EVENT_SIZE: usize> std::fmt::Debug for EventDescriptor<EVENT_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventDescriptor").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,44 @@
+use std::any::TypeId;
+
+/// Snapshot of the state of an [`EventBackend`](crate::EventBackend). Returned by [`EventBackend::diagnostics`](crate::EventBackend::diagnostics).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Diagnostics {
+    /// State of each registered event type, in registration order.
+    pub types: Vec<TypeDiagnostics>,
+}
+
+impl Diagnostics {
+    /// Returns the state of the event type with the matching id.
+    #[must_use]
+    pub fn get(&self, id: TypeId) -> Option<&TypeDiagnostics> {
+        self.types.iter().find(|typ| typ.type_id == id)
+    }
+}
+
+/// Snapshot of the state of a single registered event type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDiagnostics {
+    /// Id of the event type.
+    pub type_id: TypeId,
+
+    /// Name of the event type, as returned by [`std::any::type_name`].
+    pub type_name: &'static str,
+
+    /// Name of the store kind, e.g. `"All"` or `"Max"`, or `None` if no events are stored.
+    pub slot: Option<&'static str>,
+
+    /// Number of stored events, or `None` if no events are stored.
+    pub len: Option<usize>,
+
+    /// Maximal number of stored events, or `None` if no events are stored or the number is unbounded.
+    pub capacity: Option<usize>,
+
+    /// Events of this type are processed.
+    pub enabled: bool,
+
+    /// Number of registered event listeners.
+    pub listeners: usize,
+
+    /// Number of events dropped by the store since the last query, or `None` if no events are stored.
+    pub dropped: Option<u64>,
+}
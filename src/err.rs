@@ -17,6 +17,7 @@ impl<T: 'static, V> EventError<T, V> {
             RawErr::EventSize { max, is, t } => RawErr::EventSize { max, is, t },
 
             RawErr::RegisteredWithoutStore => RawErr::RegisteredWithoutStore,
+            RawErr::InvalidSlotParam(reason) => RawErr::InvalidSlotParam(reason),
         }
     }
 }
@@ -48,6 +49,14 @@ impl<T: 'static> EventError<T, Value> {
             raw: RawErr::UnregisteredEventType(PhantomData),
         }
     }
+
+    pub const fn registered_without_store_value(value: T) -> Self {
+        Self {
+            inner: Some(value),
+            raw: RawErr::RegisteredWithoutStore,
+            v: PhantomData,
+        }
+    }
 }
 
 impl<T: 'static> EventError<T, NoValue> {
@@ -78,6 +87,14 @@ impl<T: 'static> EventError<T, NoValue> {
             v: PhantomData,
         }
     }
+
+    pub const fn invalid_slot_param(reason: &'static str) -> Self {
+        Self {
+            inner: None,
+            raw: RawErr::InvalidSlotParam(reason),
+            v: PhantomData,
+        }
+    }
 }
 
 impl<T: 'static, V> std::error::Error for EventError<T, V> {}
@@ -112,6 +129,7 @@ pub enum RawErr<T: 'static> {
         t: PhantomData<T>,
     },
     RegisteredWithoutStore,
+    InvalidSlotParam(&'static str),
 }
 
 impl<T: 'static> std::error::Error for RawErr<T> {}
@@ -131,6 +149,11 @@ impl<T: 'static> std::fmt::Debug for RawErr<T> {
                 .finish(),
 
             Self::RegisteredWithoutStore => write!(f, "RegisteredWithListener"),
+            Self::InvalidSlotParam(reason) => f
+                .debug_struct("InvalidSlotParam")
+                .field("reason", reason)
+                .field("type", &name)
+                .finish(),
         }
     }
 }
@@ -151,6 +174,9 @@ impl<T: 'static> std::fmt::Display for RawErr<T> {
             Self::RegisteredWithoutStore => {
                 write!(f, "Event type was not registered to store events")
             }
+            Self::InvalidSlotParam(reason) => {
+                write!(f, "Invalid store parameter for event type {name}: {reason}")
+            }
         }
     }
 }
@@ -0,0 +1,40 @@
+use std::{
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Keeps an event type disabled, returned by [`EventBackend::scoped_disable`](crate::EventBackend::scoped_disable).
+///
+/// Dropping the guard enables the event type again.
+#[must_use = "the event type gets enabled again, when the guard is dropped"]
+pub struct DisableGuard<'a, T: 'static> {
+    enabled: &'a AtomicBool,
+
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: 'static> DisableGuard<'a, T> {
+    #[inline]
+    pub(crate) fn new(enabled: &'a AtomicBool) -> Self {
+        enabled.store(false, Ordering::Relaxed);
+
+        Self {
+            enabled,
+            _t: PhantomData,
+        }
+    }
+}
+
+impl<T: 'static> Drop for DisableGuard<'_, T> {
+    fn drop(&mut self) {
+        self.enabled.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<T: 'static> std::fmt::Debug for DisableGuard<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DisableGuard")
+            .field("type_name", &std::any::type_name::<T>())
+            .finish()
+    }
+}
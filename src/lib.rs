@@ -69,6 +69,12 @@
 //! }
 //! ```
 //!
+//! ## Async Listeners
+//!
+//! With the `async` feature, `EventBackend::register_async_listener()` registers listeners, that return a future.
+//! These futures are not executed by the [`EventBackend`], triggering an event only queues them.
+//! They have to be taken with `EventBackend::take_tasks()` and spawned on an async runtime, otherwise they never run.
+//!
 //!
 //!
 //!
@@ -78,12 +84,34 @@
 #![allow(clippy::module_name_repetitions)]
 
 mod backend;
+mod builder;
+mod descriptor;
+mod diagnostics;
 mod err;
+mod guard;
+mod listener;
 mod map;
+mod merge;
+mod outcome;
 mod query;
+mod set;
 mod slot;
+mod store;
+#[cfg(feature = "async")]
+mod tasks;
 
 const DEFAULT_EVENT_SIZE: usize = anythingy::DEFAULT_THING_SIZE;
 
-pub use backend::EventBackend;
+pub use backend::{Event, EventBackend};
+pub use builder::EventBackendBuilder;
+pub use descriptor::EventDescriptor;
+pub use diagnostics::{Diagnostics, TypeDiagnostics};
+pub use guard::DisableGuard;
+pub use listener::{ListenerFlow, ListenerHandle, ListenerId, Subscription};
+pub use merge::EventMerge;
+pub use outcome::EventOutcome;
+pub use set::EventSet;
 pub use slot::SlotType;
+pub use store::StoreSubscription;
+#[cfg(feature = "async")]
+pub use tasks::BoxFuture;
@@ -0,0 +1,106 @@
+use std::{
+    any::TypeId,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// Identifies a listener, registered with [`EventBackend::register_listener_handle`](crate::EventBackend::register_listener_handle).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId {
+    type_id: TypeId,
+    listener: u64,
+}
+
+impl ListenerId {
+    #[inline]
+    pub(crate) const fn new(type_id: TypeId, listener: u64) -> Self {
+        Self { type_id, listener }
+    }
+
+    /// Returns the id of the event type, the listener is registered for.
+    #[inline]
+    #[must_use]
+    pub const fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    #[inline]
+    pub(crate) const fn listener(&self) -> u64 {
+        self.listener
+    }
+}
+
+/// Returned by listeners registered with [`EventBackend::register_listener_ctl`](crate::EventBackend::register_listener_ctl).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListenerFlow {
+    /// Keep calling the listener for upcoming events.
+    Keep,
+    /// Remove the listener, after all listeners for the current event have run.
+    Remove,
+}
+
+/// Handle to a one-shot listener, registered with [`EventBackend::register_listener_once`](crate::EventBackend::register_listener_once).
+#[derive(Debug, Clone)]
+pub struct ListenerHandle {
+    done: Arc<AtomicBool>,
+}
+
+impl ListenerHandle {
+    #[inline]
+    pub(crate) const fn new(done: Arc<AtomicBool>) -> Self {
+        Self { done }
+    }
+
+    /// Cancels the listener, so it never gets called.
+    /// Returns `true`, if the listener got cancelled, or `false`, if it already got called or cancelled.
+    #[inline]
+    #[must_use]
+    pub fn cancel(&self) -> bool {
+        !self.done.swap(true, Ordering::AcqRel)
+    }
+
+    /// Returns `true`, if the listener got called or cancelled.
+    #[inline]
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.done.load(Ordering::Acquire)
+    }
+}
+
+/// Keeps a listener, registered with [`EventBackend::subscribe`](crate::EventBackend::subscribe), active.
+///
+/// Dropping the subscription removes the listener. This also works after the `EventBackend` got dropped.
+#[derive(Debug)]
+pub struct Subscription {
+    id: ListenerId,
+    active: Arc<AtomicBool>,
+}
+
+impl Subscription {
+    #[inline]
+    pub(crate) const fn new(id: ListenerId, active: Arc<AtomicBool>) -> Self {
+        Self { id, active }
+    }
+
+    /// Returns the id of the subscribed listener.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> ListenerId {
+        self.id
+    }
+
+    /// Keeps the listener registered for the lifetime of the `EventBackend`.
+    #[inline]
+    pub const fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Subscription {
+    #[inline]
+    fn drop(&mut self) {
+        self.active.store(false, Ordering::Release);
+    }
+}
@@ -1,8 +1,16 @@
-use std::any::TypeId;
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    hash::{BuildHasherDefault, Hasher},
+};
 
 use crate::backend::Registered;
 
+/// Maps event types to their registration.
+///
+/// Lookups go through a hash index, while registrations are kept in registration order for iteration.
 pub struct RegisteredMap<const SIZE: usize> {
+    index: HashMap<TypeId, usize, BuildHasherDefault<TypeIdHasher>>,
     inner: Vec<(TypeId, Registered<SIZE>)>,
 }
 
@@ -10,37 +18,60 @@ impl<const SIZE: usize> RegisteredMap<SIZE> {
     #[inline]
     #[must_use]
     pub const fn new() -> Self {
-        Self { inner: Vec::new() }
+        Self {
+            index: HashMap::with_hasher(BuildHasherDefault::new()),
+            inner: Vec::new(),
+        }
     }
 
     #[inline]
     #[must_use]
     pub fn get(&self, key: &TypeId) -> Option<&Registered<SIZE>> {
-        self.inner.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        let index = *self.index.get(key)?;
+        self.inner.get(index).map(|(_, v)| v)
     }
 
     #[inline]
     #[must_use]
     pub fn get_mut(&mut self, key: &TypeId) -> Option<&mut Registered<SIZE>> {
-        self.inner
-            .iter_mut()
-            .find(|(k, _)| k == key)
-            .map(|(_, v)| v)
+        let index = *self.index.get(key)?;
+        self.inner.get_mut(index).map(|(_, v)| v)
     }
 
+    /// Returns the registration for `key`, inserting the registration returned by `f`, if there is none yet.
     #[inline]
-    #[must_use]
-    pub fn insert(&mut self, key: TypeId, value: Registered<SIZE>) -> Option<Registered<SIZE>> {
-        let found = self.inner.iter_mut().find(|(k, _)| k == &key);
+    pub fn get_or_insert_with(
+        &mut self,
+        key: TypeId,
+        f: impl FnOnce() -> Registered<SIZE>,
+    ) -> &mut Registered<SIZE> {
+        let index = *self.index.entry(key).or_insert_with(|| {
+            self.inner.push((key, f()));
+            self.inner.len() - 1
+        });
+
+        &mut self.inner[index].1
+    }
 
-        if let Some((_, v)) = found {
-            let out = std::mem::replace(v, value);
+    /// Removes the registration, keeping the registration order of the remaining entries.
+    #[inline]
+    pub fn remove(&mut self, key: &TypeId) -> Option<Registered<SIZE>> {
+        let index = self.index.remove(key)?;
+        let (_, value) = self.inner.remove(index);
 
-            Some(out)
-        } else {
-            self.inner.push((key, value));
-            None
+        // entries after the removed one moved one position to the front
+        for (key, _) in &self.inner[index..] {
+            if let Some(i) = self.index.get_mut(key) {
+                *i -= 1;
+            }
         }
+
+        Some(value)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&TypeId, &Registered<SIZE>)> {
+        self.inner.iter().map(|(k, v)| (k, v))
     }
 
     #[inline]
@@ -65,3 +96,29 @@ impl<const SIZE: usize> RegisteredMap<SIZE> {
         self.inner.len()
     }
 }
+
+/// `TypeId`s are already hashes, so they can be used as hash directly.
+#[derive(Default)]
+pub struct TypeIdHasher {
+    hash: u64,
+}
+
+impl Hasher for TypeIdHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.hash = i;
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        // fallback, in case TypeId does not hash as a single u64
+        for byte in bytes {
+            self.hash = self.hash.rotate_left(8) ^ u64::from(*byte);
+        }
+    }
+}
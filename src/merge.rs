@@ -0,0 +1,91 @@
+use std::error::Error;
+
+use crate::{EventBackend, DEFAULT_EVENT_SIZE};
+
+type Validate<const EVENT_SIZE: usize> =
+    fn(&EventBackend<EVENT_SIZE>) -> Result<(), Box<dyn Error>>;
+
+type Drain<U, const EVENT_SIZE: usize> =
+    Box<dyn Fn(&EventBackend<EVENT_SIZE>, &mut Vec<U>) -> Result<(), Box<dyn Error>>>;
+
+/// Converters from several event types into one common type `U`.
+///
+/// Used with [`EventBackend::drain_mapped_all`] to drain events of different types into a single `Vec<U>`.
+pub struct EventMerge<U, const EVENT_SIZE: usize = DEFAULT_EVENT_SIZE> {
+    mappers: Vec<(Validate<EVENT_SIZE>, Drain<U, EVENT_SIZE>)>,
+}
+
+impl<U: 'static, const EVENT_SIZE: usize> EventMerge<U, EVENT_SIZE> {
+    /// Creates a new `EventMerge` without any converters.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            mappers: Vec::new(),
+        }
+    }
+
+    /// Adds a converter for events of type `T`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use eventsys::EventMerge;
+    /// # fn main() {
+    /// enum Input {
+    ///     Key(char),
+    ///     Click(u32),
+    /// }
+    ///
+    /// let merge: EventMerge<Input> = EventMerge::new()
+    ///     .with::<char>(Input::Key)
+    ///     .with::<u32>(Input::Click);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with<T: 'static>(mut self, map: impl Fn(T) -> U + 'static) -> Self {
+        let validate: Validate<EVENT_SIZE> =
+            |backend| backend.check_store::<T>().map_err(Into::into);
+
+        let drain = move |backend: &EventBackend<EVENT_SIZE>, out: &mut Vec<U>| {
+            let events = backend.take_slot::<T>()?;
+            out.extend(events.into_iter().map(&map));
+
+            Ok(())
+        };
+
+        self.mappers.push((validate, Box::new(drain)));
+        self
+    }
+
+    #[inline]
+    pub(crate) fn drain(
+        &self,
+        backend: &EventBackend<EVENT_SIZE>,
+    ) -> Result<Vec<U>, Box<dyn Error>> {
+        // check all types first, so nothing gets drained on error
+        for (validate, _) in &self.mappers {
+            validate(backend)?;
+        }
+
+        let mut out = Vec::new();
+
+        for (_, drain) in &self.mappers {
+            drain(backend, &mut out)?;
+        }
+
+        Ok(out)
+    }
+}
+
+impl<U: 'static, const EVENT_SIZE: usize> Default for EventMerge<U, EVENT_SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<U, const EVENT_SIZE: usize> std::fmt::Debug for EventMerge<U, EVENT_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventMerge")
+            .field("mappers", &self.mappers.len())
+            .finish()
+    }
+}
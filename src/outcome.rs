@@ -0,0 +1,16 @@
+/// Reports what happened to a triggered event. Returned by [`EventBackend::new_event_report`](crate::EventBackend::new_event_report).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EventOutcome {
+    /// Number of event listeners, that got called with the event.
+    pub listeners_invoked: usize,
+
+    /// Number of invoked event listeners, that panicked.
+    pub listeners_panicked: usize,
+
+    /// The event got stored for querying it later.
+    pub stored: bool,
+
+    /// The event type is registered to store events, but the store rejected the event,
+    /// e.g. because of a filter or a `SlotType::First` store already holding an event.
+    pub dropped_by_slot: bool,
+}
@@ -1,6 +1,6 @@
-use std::{collections::VecDeque, marker::PhantomData, sync::MutexGuard};
+use std::{collections::VecDeque, marker::PhantomData};
 
-use crate::backend::Event;
+use crate::{backend::Event, slot::SlotGuard};
 
 #[derive(Debug)]
 /// An iterator over events from type `T`.
@@ -8,7 +8,9 @@ pub struct Query<'a, T, const EVENT_SIZE: usize>
 where
     T: 'static,
 {
-    events: MutexGuard<'a, VecDeque<Event<EVENT_SIZE>>>,
+    events: SlotGuard<'a, EVENT_SIZE>,
+    /// Remaining events stay stored, after the `Query` is dropped.
+    keep_remaining: bool,
 
     _t: PhantomData<T>,
 }
@@ -19,18 +21,34 @@ where
 {
     /// Creates a new `Query` to iterate over events from type `T`.
     #[inline]
-    pub(crate) fn new(events: MutexGuard<'a, VecDeque<Event<EVENT_SIZE>>>) -> Self {
+    pub(crate) fn new(events: SlotGuard<'a, EVENT_SIZE>) -> Self {
         Self {
             events,
+            keep_remaining: false,
             _t: PhantomData,
         }
     }
 
+    /// Removes all events, for which `keep` returns `false`.
+    ///
+    /// All remaining events stay stored after this `Query` is dropped, unless they get consumed by iterating.
+    #[inline]
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        self.events.retain(|event| keep(event.get_ref::<T>()));
+        self.keep_remaining = true;
+    }
+
     #[inline]
     /// Returns the number of events this `Query` can produce.
     pub fn len(&self) -> usize {
         self.events.len()
     }
+
+    #[inline]
+    /// Returns `true`, if this `Query` produces no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
 }
 
 impl<'a, T, const EVENT_SIZE: usize> Iterator for Query<'a, T, EVENT_SIZE>
@@ -45,6 +63,24 @@ where
 
         out.map(Event::get)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.events.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const EVENT_SIZE: usize> ExactSizeIterator for Query<'_, T, EVENT_SIZE> where T: 'static {}
+
+impl<T, const EVENT_SIZE: usize> DoubleEndedIterator for Query<'_, T, EVENT_SIZE>
+where
+    T: 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.events.pop_back().map(Event::get)
+    }
 }
 
 impl<'a, T, const EVENT_SIZE: usize> Drop for Query<'a, T, EVENT_SIZE>
@@ -53,6 +89,11 @@ where
 {
     #[inline]
     fn drop(&mut self) {
+        // keep remaining events, if the consumer panicked while iterating
+        if self.keep_remaining || std::thread::panicking() {
+            return;
+        }
+
         self.events.clear();
     }
 }
@@ -85,11 +126,26 @@ where
         }
     }
 
+    /// Removes all events, for which `keep` returns `false`.
+    ///
+    /// The events of an `UnblockingQuery` are already taken out of the store,
+    /// so the remaining events are not written back and get dropped with this query.
+    #[inline]
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        self.events.retain(|event| keep(event.get_ref::<T>()));
+    }
+
     #[inline]
     /// Returns the number of events this `Query` can produce.
     pub fn len(&self) -> usize {
         self.events.len()
     }
+
+    #[inline]
+    /// Returns `true`, if this `Query` produces no events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
 }
 
 impl<T, const EVENT_SIZE: usize> Iterator for UnblockingQuery<T, EVENT_SIZE>
@@ -104,6 +160,27 @@ where
 
         out.map(Event::get)
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.events.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const EVENT_SIZE: usize> ExactSizeIterator for UnblockingQuery<T, EVENT_SIZE> where
+    T: 'static
+{
+}
+
+impl<T, const EVENT_SIZE: usize> DoubleEndedIterator for UnblockingQuery<T, EVENT_SIZE>
+where
+    T: 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.events.pop_back().map(Event::get)
+    }
 }
 
 impl<T, const EVENT_SIZE: usize> Drop for UnblockingQuery<T, EVENT_SIZE>
@@ -115,3 +192,96 @@ where
         self.events.clear();
     }
 }
+
+// ############################
+// ############################
+// ############################
+
+#[derive(Debug)]
+/// A view over events from type `T`, that keeps the events stored.
+pub struct PeekQuery<'a, T, const EVENT_SIZE: usize>
+where
+    T: 'static,
+{
+    events: SlotGuard<'a, EVENT_SIZE>,
+
+    _t: PhantomData<T>,
+}
+
+impl<'a, T, const EVENT_SIZE: usize> PeekQuery<'a, T, EVENT_SIZE>
+where
+    T: 'static,
+{
+    /// Creates a new `PeekQuery` to iterate over events from type `T`.
+    #[inline]
+    pub(crate) const fn new(events: SlotGuard<'a, EVENT_SIZE>) -> Self {
+        Self {
+            events,
+            _t: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over references to the stored events.
+    #[inline]
+    pub fn iter(&self) -> PeekIter<'_, T, EVENT_SIZE> {
+        PeekIter {
+            events: self.events.iter(),
+            _t: PhantomData,
+        }
+    }
+
+    #[inline]
+    /// Returns the number of stored events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    #[inline]
+    /// Returns `true`, if no events are stored.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl<'q, T, const EVENT_SIZE: usize> IntoIterator for &'q PeekQuery<'_, T, EVENT_SIZE>
+where
+    T: 'static,
+{
+    type Item = &'q T;
+    type IntoIter = PeekIter<'q, T, EVENT_SIZE>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Debug)]
+/// An iterator over references to events from type `T`. Created by [`PeekQuery::iter`].
+pub struct PeekIter<'q, T, const EVENT_SIZE: usize>
+where
+    T: 'static,
+{
+    events: std::collections::vec_deque::Iter<'q, Event<EVENT_SIZE>>,
+
+    _t: PhantomData<T>,
+}
+
+impl<'q, T, const EVENT_SIZE: usize> Iterator for PeekIter<'q, T, EVENT_SIZE>
+where
+    T: 'static,
+{
+    type Item = &'q T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.events.next().map(Event::get_ref)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.events.size_hint()
+    }
+}
+
+impl<T, const EVENT_SIZE: usize> ExactSizeIterator for PeekIter<'_, T, EVENT_SIZE> where T: 'static {}
@@ -0,0 +1,52 @@
+use std::error::Error;
+
+use crate::EventBackend;
+
+/// A statically known set of event types, that can be drained together with [`EventBackend::drain_set`].
+///
+/// Implemented for tuples of up to 8 event types.
+pub trait EventSet<const EVENT_SIZE: usize> {
+    /// Tuple of the drained events, one `Vec` per event type.
+    type Output;
+
+    /// Checks that every event type of the set can be drained from the backend.
+    ///
+    /// # Errors
+    /// Returns the error of the first event type, that was not registered to store events.
+    fn validate(backend: &EventBackend<EVENT_SIZE>) -> Result<(), Box<dyn Error>>;
+
+    /// Takes all stored events of every event type of the set.
+    ///
+    /// # Errors
+    /// Returns the error of the first event type, that was not registered to store events.
+    fn drain(backend: &EventBackend<EVENT_SIZE>) -> Result<Self::Output, Box<dyn Error>>;
+}
+
+macro_rules! impl_event_set {
+    ($($t:ident),+) => {
+        impl<const EVENT_SIZE: usize, $($t: 'static),+> EventSet<EVENT_SIZE> for ($($t,)+) {
+            type Output = ($(Vec<$t>,)+);
+
+            fn validate(backend: &EventBackend<EVENT_SIZE>) -> Result<(), Box<dyn Error>> {
+                $(backend.check_store::<$t>()?;)+
+                Ok(())
+            }
+
+            fn drain(backend: &EventBackend<EVENT_SIZE>) -> Result<Self::Output, Box<dyn Error>> {
+                // check all types first, so nothing gets drained on error
+                Self::validate(backend)?;
+
+                Ok(($(Vec::from(backend.take_slot::<$t>()?),)+))
+            }
+        }
+    };
+}
+
+impl_event_set!(A);
+impl_event_set!(A, B);
+impl_event_set!(A, B, C);
+impl_event_set!(A, B, C, D);
+impl_event_set!(A, B, C, D, E);
+impl_event_set!(A, B, C, D, E, F);
+impl_event_set!(A, B, C, D, E, F, G);
+impl_event_set!(A, B, C, D, E, F, G, H);
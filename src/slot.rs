@@ -1,6 +1,15 @@
 use std::{
-    collections::VecDeque,
-    sync::{Mutex, MutexGuard, PoisonError},
+    any::Any,
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    hash::{BuildHasher, Hash, RandomState},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex, MutexGuard, PoisonError, TryLockError,
+    },
+    time::{Duration, Instant},
 };
 
 use crate::backend::Event;
@@ -10,7 +19,32 @@ type Cmp<const SIZE: usize> =
 
 type Filter<const SIZE: usize> = Box<dyn Fn(&Event<SIZE>) -> bool + Send + Sync + 'static>;
 
-pub enum Slot<const SIZE: usize> {
+type Order<const SIZE: usize> =
+    Box<dyn Fn(&Event<SIZE>, &Event<SIZE>) -> std::cmp::Ordering + Send + Sync + 'static>;
+
+type Evict<const SIZE: usize> = Box<dyn Fn(Event<SIZE>) + Send + Sync + 'static>;
+
+type Merge<const SIZE: usize> =
+    Box<dyn Fn(Event<SIZE>, &Event<SIZE>) -> Event<SIZE> + Send + Sync + 'static>;
+
+type KeyHash<const SIZE: usize> = Box<dyn Fn(&Event<SIZE>) -> u64 + Send + Sync + 'static>;
+
+type Accumulator = Option<Box<dyn Any + Send>>;
+
+type Fold<const SIZE: usize> = Box<dyn Fn(&mut Accumulator, &Event<SIZE>) + Send + Sync + 'static>;
+
+pub struct Slot<const SIZE: usize> {
+    kind: SlotKind<SIZE>,
+
+    /// Number of stored events, updated whenever a guard to the stored events is released.
+    /// Allows checking for stored events without locking.
+    len: AtomicUsize,
+
+    /// Number of events kept apart from the stored events, updated while holding the lock of the kept events.
+    kept: AtomicUsize,
+}
+
+pub enum SlotKind<const SIZE: usize> {
     All(Mutex<VecDeque<Event<SIZE>>>),
     Last(Mutex<VecDeque<Event<SIZE>>>),
     First(Mutex<VecDeque<Event<SIZE>>>),
@@ -23,20 +57,123 @@ pub enum Slot<const SIZE: usize> {
         inner: Mutex<VecDeque<Event<SIZE>>>,
         filter: Filter<SIZE>,
     },
+    Dedup {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        eq: Cmp<SIZE>,
+    },
+    Distinct {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        eq: Cmp<SIZE>,
+    },
+    SortedBy {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        cmp: Order<SIZE>,
+    },
+    Coalesce {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        fold: Merge<SIZE>,
+    },
+    Top {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        cmp: Order<SIZE>,
+        n: usize,
+    },
+    Bottom {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        cmp: Order<SIZE>,
+        n: usize,
+    },
+    Sample {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        n: usize,
+        /// Number of pushed events, including the events that got skipped.
+        counter: AtomicUsize,
+    },
     Max {
         inner: Mutex<VecDeque<Event<SIZE>>>,
         max: usize,
+        on_evict: Option<Evict<SIZE>>,
+    },
+    Bounded {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        capacity: usize,
+    },
+    Reservoir {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        k: usize,
+        /// Number of events pushed since the stored events were last queried, including the events that got skipped.
+        counter: AtomicUsize,
+        /// State of the random number generator, only updated while holding the guard of `inner`.
+        rng: AtomicU64,
     },
+    Unique {
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        eq: Cmp<SIZE>,
+        capacity: usize,
+    },
+    Inline {
+        /// Only holds events while they are locked, all other events are kept in `staged`.
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        staged: Mutex<Box<dyn Staged<SIZE>>>,
+        capacity: usize,
+    },
+    Counting {
+        /// Never holds any events, all events are counted in `counted`.
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        counted: Mutex<Counted<SIZE>>,
+        hash: KeyHash<SIZE>,
+        eq: Cmp<SIZE>,
+    },
+    LatestByKey {
+        /// Only holds events while they are locked, all other events are kept in `staged`.
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        staged: Mutex<Box<dyn Staged<SIZE>>>,
+    },
+    Accumulate {
+        /// Never holds any events, all events are folded into `acc`.
+        inner: Mutex<VecDeque<Event<SIZE>>>,
+        acc: Mutex<Accumulator>,
+        fold: Fold<SIZE>,
+    },
+}
+
+/// Outcome of storing an event in a slot.
+#[derive(Debug, Clone, Copy)]
+pub struct Pushed {
+    /// The new event got stored.
+    pub stored: bool,
+    /// An event got dropped, either the new event or a previously stored one.
+    pub dropped: bool,
+}
+
+impl Pushed {
+    /// The new event got dropped.
+    const REJECTED: Self = Self {
+        stored: false,
+        dropped: true,
+    };
+
+    /// The new event got stored, `dropped` reports if a previously stored event got dropped.
+    #[inline]
+    const fn stored(dropped: bool) -> Self {
+        Self {
+            stored: true,
+            dropped,
+        }
+    }
 }
 
+/// Upper bound for the storage allocated on registration of size limited stores.
+const MAX_PREALLOC: usize = 1024;
+
 impl<const SIZE: usize> Slot<SIZE> {
     #[inline]
     #[allow(clippy::needless_pass_by_value)]
     pub fn new<T: 'static>(typ: SlotType<T>) -> Self {
-        match typ {
-            SlotType::All => Self::All(Mutex::new(VecDeque::with_capacity(64))),
-            SlotType::Last => Self::Last(Mutex::new(VecDeque::with_capacity(1))),
-            SlotType::First => Self::First(Mutex::new(VecDeque::with_capacity(1))),
+        let kind = match typ {
+            SlotType::All => SlotKind::All(Mutex::new(VecDeque::with_capacity(64))),
+            SlotType::Last => SlotKind::Last(Mutex::new(VecDeque::with_capacity(1))),
+            SlotType::First => SlotKind::First(Mutex::new(VecDeque::with_capacity(1))),
             SlotType::Cmp(cmp) => {
                 let f = move |current: &Event<SIZE>, new: &Event<SIZE>| {
                     let c = current.get_ref::<T>();
@@ -45,7 +182,7 @@ impl<const SIZE: usize> Slot<SIZE> {
                     cmp(c, n)
                 };
 
-                Self::Cmp {
+                SlotKind::Cmp {
                     inner: Mutex::new(VecDeque::with_capacity(1)),
                     cmp: Box::new(f),
                 }
@@ -57,176 +194,1339 @@ impl<const SIZE: usize> Slot<SIZE> {
                     filter(n)
                 };
 
-                Self::AllFilter {
+                SlotKind::AllFilter {
                     inner: Mutex::new(VecDeque::with_capacity(32)),
                     filter: Box::new(f),
                 }
             }
-            SlotType::Max(max) => Self::Max {
-                inner: Mutex::new(VecDeque::with_capacity(max / 2)),
+            SlotType::CmpFn(cmp) => SlotKind::Cmp {
+                inner: Mutex::new(VecDeque::with_capacity(1)),
+                cmp: Self::cmp::<T>(cmp),
+            },
+            SlotType::FilterFn(filter) => SlotKind::AllFilter {
+                inner: Mutex::new(VecDeque::with_capacity(32)),
+                filter: Self::filter::<T>(filter),
+            },
+            SlotType::Dedup(eq) => SlotKind::Dedup {
+                inner: Mutex::new(VecDeque::with_capacity(32)),
+                eq: Self::cmp::<T>(eq),
+            },
+            SlotType::Distinct(eq) => SlotKind::Distinct {
+                inner: Mutex::new(VecDeque::with_capacity(32)),
+                eq: Self::cmp::<T>(eq),
+            },
+            SlotType::SortedBy(cmp) => SlotKind::SortedBy {
+                inner: Mutex::new(VecDeque::with_capacity(32)),
+                cmp: Self::order::<T>(cmp),
+            },
+            SlotType::Coalesce(fold) => SlotKind::Coalesce {
+                inner: Mutex::new(VecDeque::with_capacity(1)),
+                fold: Self::merge::<T>(fold),
+            },
+            SlotType::CoalesceFn(fold) => SlotKind::Coalesce {
+                inner: Mutex::new(VecDeque::with_capacity(1)),
+                fold: Self::merge::<T>(fold),
+            },
+            SlotType::Top(n, cmp) => SlotKind::Top {
+                inner: Mutex::new(VecDeque::with_capacity(
+                    n.saturating_add(1).min(MAX_PREALLOC),
+                )),
+                cmp: Self::order::<T>(cmp),
+                n,
+            },
+            SlotType::Bottom(n, cmp) => SlotKind::Bottom {
+                inner: Mutex::new(VecDeque::with_capacity(
+                    n.saturating_add(1).min(MAX_PREALLOC),
+                )),
+                cmp: Self::order::<T>(cmp),
+                n,
+            },
+            SlotType::Sample(n) => SlotKind::Sample {
+                inner: Mutex::new(VecDeque::with_capacity(32)),
+                n,
+                counter: AtomicUsize::new(0),
+            },
+            SlotType::Max(max) => SlotKind::Max {
+                inner: Mutex::new(VecDeque::with_capacity((max / 2).clamp(1, MAX_PREALLOC))),
                 max,
+                on_evict: None,
             },
+            SlotType::Bounded(capacity) => SlotKind::Bounded {
+                inner: Mutex::new(VecDeque::with_capacity(capacity.min(MAX_PREALLOC))),
+                capacity,
+            },
+            SlotType::Reservoir(k) => Self::reservoir(k, RandomState::new().hash_one(k)),
+            SlotType::ReservoirSeeded(k, seed) => Self::reservoir(k, seed),
+        };
+
+        Self::from_kind(kind)
+    }
+
+    #[inline]
+    fn reservoir(k: usize, seed: u64) -> SlotKind<SIZE> {
+        SlotKind::Reservoir {
+            inner: Mutex::new(VecDeque::with_capacity(k.min(MAX_PREALLOC))),
+            k,
+            counter: AtomicUsize::new(0),
+            rng: AtomicU64::new(seed),
         }
     }
 
+    /// Wraps a comparison of events of type `T`.
     #[inline]
-    pub fn push(&self, value: Event<SIZE>) {
-        match self {
+    fn cmp<T: 'static>(cmp: impl Fn(&T, &T) -> bool + Send + Sync + 'static) -> Cmp<SIZE> {
+        let f = move |current: &Event<SIZE>, new: &Event<SIZE>| {
+            let c = current.get_ref::<T>();
+            let n = new.get_ref::<T>();
+
+            cmp(c, n)
+        };
+
+        Box::new(f)
+    }
+
+    /// Wraps a filter of events of type `T`.
+    #[inline]
+    fn filter<T: 'static>(filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> Filter<SIZE> {
+        let f = move |new: &Event<SIZE>| {
+            let n = new.get_ref::<T>();
+
+            filter(n)
+        };
+
+        Box::new(f)
+    }
+
+    /// Wraps an ordering of events of type `T`.
+    #[inline]
+    fn order<T: 'static>(
+        cmp: impl Fn(&T, &T) -> std::cmp::Ordering + Send + Sync + 'static,
+    ) -> Order<SIZE> {
+        let f = move |a: &Event<SIZE>, b: &Event<SIZE>| {
+            let a = a.get_ref::<T>();
+            let b = b.get_ref::<T>();
+
+            cmp(a, b)
+        };
+
+        Box::new(f)
+    }
+
+    /// Wraps a fold of events of type `T`, that merges the new event into the stored event.
+    #[inline]
+    fn merge<T: 'static>(fold: impl Fn(&mut T, &T) + Send + Sync + 'static) -> Merge<SIZE> {
+        let f = move |current: Event<SIZE>, new: &Event<SIZE>| {
+            let mut acc = current.get::<T>();
+            fold(&mut acc, new.get_ref::<T>());
+
+            Event::new(acc)
+        };
+
+        Box::new(f)
+    }
+
+    #[inline]
+    pub fn max_with_evict<T: 'static>(
+        max: usize,
+        on_evict: impl Fn(T) + Send + Sync + 'static,
+    ) -> Self {
+        let f = move |evicted: Event<SIZE>| on_evict(evicted.get::<T>());
+
+        Self::from_kind(SlotKind::Max {
+            inner: Mutex::new(VecDeque::with_capacity((max / 2).clamp(1, MAX_PREALLOC))),
+            max,
+            on_evict: Some(Box::new(f)),
+        })
+    }
+
+    #[inline]
+    pub fn unique<T: Eq + 'static>(capacity: usize) -> Self {
+        let f = |current: &Event<SIZE>, new: &Event<SIZE>| {
+            let c = current.get_ref::<T>();
+            let n = new.get_ref::<T>();
+
+            c == n
+        };
+
+        Self::from_kind(SlotKind::Unique {
+            inner: Mutex::new(VecDeque::with_capacity(capacity.min(MAX_PREALLOC))),
+            eq: Box::new(f),
+            capacity,
+        })
+    }
+
+    /// Creates a slot, that stores the last `N` events in an inline array.
+    ///
+    /// `N` has to be at least 1.
+    #[inline]
+    pub fn inline<const N: usize>() -> Self {
+        let ring = Ring::<SIZE, N> {
+            events: std::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+        };
+
+        Self::from_kind(SlotKind::Inline {
+            inner: Mutex::new(VecDeque::new()),
+            staged: Mutex::new(Box::new(ring)),
+            capacity: N,
+        })
+    }
+
+    #[inline]
+    pub fn counting<T: Eq + Hash + 'static>() -> Self {
+        let state = RandomState::new();
+        let hash = move |event: &Event<SIZE>| state.hash_one(event.get_ref::<T>());
+
+        let f = |current: &Event<SIZE>, new: &Event<SIZE>| {
+            let c = current.get_ref::<T>();
+            let n = new.get_ref::<T>();
+
+            c == n
+        };
+
+        Self::from_kind(SlotKind::Counting {
+            inner: Mutex::new(VecDeque::new()),
+            counted: Mutex::new(Counted::default()),
+            hash: Box::new(hash),
+            eq: Box::new(f),
+        })
+    }
+
+    #[inline]
+    pub fn latest_by_key<T: 'static, K: Hash + Eq + Send + 'static>(
+        key: impl Fn(&T) -> K + Send + Sync + 'static,
+    ) -> Self {
+        let keyed = Keyed::<SIZE, T, K, _> {
+            key,
+            index: HashMap::new(),
+            entries: Vec::new(),
+            _t: PhantomData,
+        };
+
+        Self::from_kind(SlotKind::LatestByKey {
+            inner: Mutex::new(VecDeque::new()),
+            staged: Mutex::new(Box::new(keyed)),
+        })
+    }
+
+    #[inline]
+    pub fn accumulate<T: 'static, A: Clone + Send + Sync + 'static>(
+        init: A,
+        fold: fn(&mut A, &T),
+    ) -> Self {
+        let f = move |acc: &mut Accumulator, event: &Event<SIZE>| {
+            let acc = acc.get_or_insert_with(|| Box::new(init.clone()));
+
+            // the accumulator is only ever created by this closure, so it always has type A
+            if let Some(acc) = acc.downcast_mut::<A>() {
+                fold(acc, event.get_ref::<T>());
+            }
+        };
+
+        Self::from_kind(SlotKind::Accumulate {
+            inner: Mutex::new(VecDeque::new()),
+            acc: Mutex::new(None),
+            fold: Box::new(f),
+        })
+    }
+
+    #[inline]
+    const fn from_kind(kind: SlotKind<SIZE>) -> Self {
+        Self {
+            kind,
+            len: AtomicUsize::new(0),
+            kept: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of stored events, without locking the stored events.
+    ///
+    /// Events pushed or removed while a guard is held are only counted, after the guard is released.
+    /// Counting slots return the number of distinct counted events.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed) + self.kept.load(Ordering::Relaxed)
+    }
+
+    /// Returns the name of the slot type.
+    #[inline]
+    pub const fn kind_name(&self) -> &'static str {
+        match &self.kind {
+            SlotKind::All(_) => "All",
+            SlotKind::Last(_) => "Last",
+            SlotKind::First(_) => "First",
+            SlotKind::Cmp { .. } => "Cmp",
+            SlotKind::AllFilter { .. } => "AllFilter",
+            SlotKind::Dedup { .. } => "Dedup",
+            SlotKind::Distinct { .. } => "Distinct",
+            SlotKind::SortedBy { .. } => "SortedBy",
+            SlotKind::Coalesce { .. } => "Coalesce",
+            SlotKind::Top { .. } => "Top",
+            SlotKind::Bottom { .. } => "Bottom",
+            SlotKind::Sample { .. } => "Sample",
+            SlotKind::Max { .. } => "Max",
+            SlotKind::Bounded { .. } => "Bounded",
+            SlotKind::Reservoir { .. } => "Reservoir",
+            SlotKind::Unique { .. } => "Unique",
+            SlotKind::Inline { .. } => "Inline",
+            SlotKind::Counting { .. } => "Counting",
+            SlotKind::LatestByKey { .. } => "LatestByKey",
+            SlotKind::Accumulate { .. } => "Accumulate",
+        }
+    }
+
+    /// Returns the maximal number of stored events, or `None` if the number is unbounded.
+    #[inline]
+    pub const fn capacity(&self) -> Option<usize> {
+        match &self.kind {
+            SlotKind::Last(_)
+            | SlotKind::First(_)
+            | SlotKind::Cmp { .. }
+            | SlotKind::Coalesce { .. } => Some(1),
+            SlotKind::Max { max: capacity, .. }
+            | SlotKind::Unique { capacity, .. }
+            | SlotKind::Inline { capacity, .. }
+            | SlotKind::Bounded { capacity, .. }
+            | SlotKind::Reservoir { k: capacity, .. }
+            | SlotKind::Top { n: capacity, .. }
+            | SlotKind::Bottom { n: capacity, .. } => Some(*capacity),
+            SlotKind::All(_)
+            | SlotKind::AllFilter { .. }
+            | SlotKind::Dedup { .. }
+            | SlotKind::Distinct { .. }
+            | SlotKind::SortedBy { .. }
+            | SlotKind::Sample { .. }
+            | SlotKind::Counting { .. }
+            | SlotKind::LatestByKey { .. }
+            | SlotKind::Accumulate { .. } => None,
+        }
+    }
+
+    /// Stores a new event, according to the slot type.
+    #[inline]
+    pub fn push(&self, value: Event<SIZE>) -> Pushed {
+        // rejected events don't need to lock the stored events
+        if !self.admit(&value) {
+            return Pushed::REJECTED;
+        }
+
+        match &self.kind {
+            // store distinct events and count occurrences
+            SlotKind::Counting {
+                counted, hash, eq, ..
+            } => {
+                self.with_counted(counted, |counted| counted.count(hash(&value), value, eq));
+
+                Pushed::stored(false)
+            }
+
+            // fold events into a single value, the event itself is not stored
+            SlotKind::Accumulate { acc, fold, .. } => {
+                // we have full control over the lock, there should never be a panic while holding the guard
+                let mut acc = acc.lock().unwrap_or_else(PoisonError::into_inner);
+                fold(&mut acc, &value);
+
+                Pushed::stored(false)
+            }
+
+            // store events apart from the locked stored events
+            SlotKind::Inline { staged, .. } | SlotKind::LatestByKey { staged, .. } => {
+                self.push_staged(&mut lock_staged(staged), value)
+            }
+
+            _ => self.push_locked(&mut self.events(), value),
+        }
+    }
+
+    /// Returns `false`, if the event gets rejected by the filter function or skipped by sampling.
+    #[inline]
+    fn admit(&self, value: &Event<SIZE>) -> bool {
+        match &self.kind {
+            // use custom filter function
+            SlotKind::AllFilter { filter, .. } => filter(value),
+
+            // store every nth event
+            SlotKind::Sample { n, counter, .. } => {
+                let count = counter.fetch_add(1, Ordering::Relaxed);
+
+                *n != 0 && count.is_multiple_of(*n)
+            }
+
+            _ => true,
+        }
+    }
+
+    /// Stores a new event in the already locked stored events, according to the slot type.
+    ///
+    /// Filtering and sampling already happened in [`Slot::admit`], so these slots store every event.
+    #[inline]
+    fn push_locked(&self, events: &mut VecDeque<Event<SIZE>>, value: Event<SIZE>) -> Pushed {
+        match &self.kind {
             // store all events
-            Self::All(lock) => {
-                // we have full controll over the lock, there should never be a panick while holding the guard
-                let mut guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
-                guard.push_back(value);
+            SlotKind::All(_) | SlotKind::AllFilter { .. } | SlotKind::Sample { .. } => {
+                events.push_back(value);
+
+                Pushed::stored(false)
             }
 
             // store only the last
-            Self::Last(lock) => {
-                // we have full controll over the lock, there should never be a panick while holding the guard
-                let mut guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
-
+            SlotKind::Last(_) => {
                 // try to pop the current value
-                _ = guard.pop_back();
+                let dropped = events.pop_back().is_some();
 
                 // insert new value
-                guard.push_back(value);
+                events.push_back(value);
+
+                Pushed::stored(dropped)
             }
 
             // store only the first
-            Self::First(lock) => {
-                // we have full controll over the lock, there should never be a panick while holding the guard
-                let mut guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
-
+            SlotKind::First(_) => {
                 // if no event is stored, store input
-                if guard.is_empty() {
-                    guard.push_front(value);
+                if events.is_empty() {
+                    events.push_front(value);
+                    Pushed::stored(false)
+                } else {
+                    Pushed::REJECTED
                 }
             }
 
             // use custom compare function
-            Self::Cmp { inner, cmp } => {
-                // we have full controll over the lock, there should never be a panick while holding the guard
-                let mut guard = inner.lock().unwrap_or_else(PoisonError::into_inner);
-
-                if let Some(curr) = guard.front_mut() {
-                    // check if value should be replaced
+            SlotKind::Cmp { cmp, .. } => {
+                if let Some(curr) = events.front_mut() {
+                    // check if value should be replaced, either the current or the new value gets dropped
                     if cmp(curr, &value) {
                         *curr = value;
+                        return Pushed::stored(true);
                     }
-                } else {
-                    guard.push_front(value);
+
+                    return Pushed::REJECTED;
                 }
+
+                events.push_front(value);
+
+                Pushed::stored(false)
             }
 
-            // use custom filter function
-            Self::AllFilter { inner, filter: cmp } => {
-                if !cmp(&value) {
-                    return;
+            // skip events equal to the most recently stored event
+            SlotKind::Dedup { eq, .. } => {
+                if events.back().is_some_and(|last| eq(last, &value)) {
+                    return Pushed::REJECTED;
                 }
 
-                // we have full controll over the lock, there should never be a panick while holding the guard
-                let mut guard = inner.lock().unwrap_or_else(PoisonError::into_inner);
-                guard.push_back(value);
+                events.push_back(value);
+
+                Pushed::stored(false)
+            }
+
+            // skip events equal to any stored event
+            SlotKind::Distinct { eq, .. } => {
+                if events.iter().any(|current| eq(current, &value)) {
+                    return Pushed::REJECTED;
+                }
+
+                events.push_back(value);
+
+                Pushed::stored(false)
+            }
+
+            // store events in sorted order, equal events keep their order
+            SlotKind::SortedBy { cmp, .. } => {
+                let index = events
+                    .partition_point(|current| cmp(current, &value) != std::cmp::Ordering::Greater);
+                events.insert(index, value);
+
+                Pushed::stored(false)
+            }
+
+            // fold all events into a single stored event
+            SlotKind::Coalesce { fold, .. } => {
+                let merged = match events.pop_front() {
+                    Some(current) => fold(current, &value),
+                    None => value,
+                };
+                events.push_back(merged);
+
+                Pushed::stored(false)
+            }
+
+            // store the n largest or smallest events, ordered from the largest or smallest
+            SlotKind::Top { cmp, n, .. } => {
+                Self::push_ranked(events, *n, |a, b| cmp(a, b).reverse(), value)
             }
+            SlotKind::Bottom { cmp, n, .. } => Self::push_ranked(events, *n, cmp, value),
 
             // store all events up to specified number
-            Self::Max { inner, max } => {
-                // we have full controll over the lock, there should never be a panick while holding the guard
-                let mut guard = inner.lock().unwrap_or_else(PoisonError::into_inner);
+            SlotKind::Max { max, on_evict, .. } => {
+                Self::push_bounded(events, *max, on_evict.as_ref(), value)
+            }
 
-                if guard.len() == *max {
-                    // remove oldest value
-                    guard.pop_front();
+            // store events up to the capacity, new events get rejected while full
+            SlotKind::Bounded { capacity, .. } => {
+                if events.len() >= *capacity {
+                    return Pushed::REJECTED;
                 }
-                // put new value in
-                guard.push_back(value);
+
+                events.push_back(value);
+
+                Pushed::stored(false)
+            }
+
+            // store a uniformly random sample of k events
+            SlotKind::Reservoir {
+                k, counter, rng, ..
+            } => Self::push_reservoir(events, *k, counter, rng, value),
+
+            // store distinct events, ordered by recency
+            SlotKind::Unique { eq, capacity, .. } => {
+                Self::push_unique(events, eq, *capacity, value)
+            }
+
+            // counting, accumulating, inline and keyed slots never lock the stored events to store a new event
+            SlotKind::Counting { .. }
+            | SlotKind::Accumulate { .. }
+            | SlotKind::Inline { .. }
+            | SlotKind::LatestByKey { .. } => self.push(value),
+        }
+    }
+
+    /// Stores all events, according to the slot type. Returns the number of dropped events.
+    ///
+    /// The stored or staged events are only locked once for all events, counting and accumulating slots store events
+    /// one by one, as they never lock the stored events.
+    #[inline]
+    pub fn push_all(&self, events: Vec<Event<SIZE>>) -> usize {
+        match &self.kind {
+            SlotKind::Counting { .. } | SlotKind::Accumulate { .. } => events
+                .into_iter()
+                .map(|event| self.push(event))
+                .filter(|pushed| pushed.dropped)
+                .count(),
+
+            SlotKind::Inline { staged, .. } | SlotKind::LatestByKey { staged, .. } => {
+                let staged = &mut *lock_staged(staged);
+
+                events
+                    .into_iter()
+                    .map(|event| self.push_staged(staged, event))
+                    .filter(|pushed| pushed.dropped)
+                    .count()
             }
+
+            _ => self.push_all_locked(&mut self.events(), events),
+        }
+    }
+
+    /// Stores all events in the already locked stored events. Returns the number of dropped events.
+    #[inline]
+    fn push_all_locked(
+        &self,
+        stored: &mut VecDeque<Event<SIZE>>,
+        events: Vec<Event<SIZE>>,
+    ) -> usize {
+        if let SlotKind::All(_) = &self.kind {
+            stored.extend(events);
+            return 0;
         }
+
+        events
+            .into_iter()
+            .map(|event| {
+                if self.admit(&event) {
+                    self.push_locked(stored, event)
+                } else {
+                    Pushed::REJECTED
+                }
+            })
+            .filter(|pushed| pushed.dropped)
+            .count()
     }
 
     #[inline]
-    pub fn events(&self) -> MutexGuard<VecDeque<Event<SIZE>>> {
-        let lock = match self {
-            Self::All(lock) | Self::Last(lock) | Self::First(lock) => lock,
-            Self::Cmp { inner, cmp: _ }
-            | Self::AllFilter { inner, filter: _ }
-            | Self::Max { inner, max: _ } => inner,
+    fn push_bounded(
+        events: &mut VecDeque<Event<SIZE>>,
+        max: usize,
+        on_evict: Option<&Evict<SIZE>>,
+        value: Event<SIZE>,
+    ) -> Pushed {
+        if max == 0 {
+            // nothing can be stored, the new event gets dropped
+            return Pushed::REJECTED;
+        }
+
+        let dropped = if events.len() >= max {
+            // remove oldest value
+            match (events.pop_front(), on_evict) {
+                (Some(evicted), Some(on_evict)) => {
+                    // hand over the evicted value, a panicking callback should not leave the slot in an invalid state
+                    _ = std::panic::catch_unwind(AssertUnwindSafe(|| on_evict(evicted)));
+                    true
+                }
+                (evicted, _) => evicted.is_some(),
+            }
+        } else {
+            false
         };
 
-        // we have full controll over the lock, there should never be a panick while holding the guard
-        lock.lock().unwrap_or_else(PoisonError::into_inner)
+        // put new value in
+        events.push_back(value);
+
+        Pushed::stored(dropped)
     }
 
     #[inline]
-    pub fn events_clone(&self) -> VecDeque<Event<SIZE>> {
-        let lock = match self {
-            Self::All(lock) | Self::Last(lock) | Self::First(lock) => lock,
-            Self::Cmp { inner, cmp: _ }
-            | Self::AllFilter { inner, filter: _ }
-            | Self::Max { inner, max: _ } => inner,
+    fn push_unique(
+        events: &mut VecDeque<Event<SIZE>>,
+        eq: &Cmp<SIZE>,
+        capacity: usize,
+        value: Event<SIZE>,
+    ) -> Pushed {
+        let dropped = if let Some(index) = events.iter().position(|current| eq(current, &value)) {
+            // remove equal value, new value becomes the most recent
+            _ = events.remove(index);
+            false
+        } else if events.len() >= capacity {
+            // remove least recently pushed value
+            events.pop_front().is_some()
+        } else {
+            false
         };
 
+        // put new value in
+        events.push_back(value);
+
+        Pushed::stored(dropped)
+    }
+
+    /// Stores the event using reservoir sampling (Algorithm R), so every pushed event is stored with the same probability.
+    #[inline]
+    fn push_reservoir(
+        events: &mut VecDeque<Event<SIZE>>,
+        k: usize,
+        counter: &AtomicUsize,
+        rng: &AtomicU64,
+        value: Event<SIZE>,
+    ) -> Pushed {
+        // fill the reservoir first, a queried reservoir starts sampling again
+        if events.len() < k {
+            events.push_back(value);
+            counter.store(events.len(), Ordering::Relaxed);
+
+            return Pushed::stored(false);
+        }
+
+        let seen = counter.fetch_add(1, Ordering::Relaxed) + 1;
+
+        // replace a random event with probability k / seen
+        let index = random_below(rng, seen);
+        if let Some(current) = events.get_mut(index) {
+            *current = value;
+            return Pushed::stored(true);
+        }
+
+        Pushed::REJECTED
+    }
+
+    /// Stores the event ordered by `rank`, keeping only the first `n` events.
+    #[inline]
+    fn push_ranked(
+        events: &mut VecDeque<Event<SIZE>>,
+        n: usize,
+        rank: impl Fn(&Event<SIZE>, &Event<SIZE>) -> std::cmp::Ordering,
+        value: Event<SIZE>,
+    ) -> Pushed {
+        // equal events keep their order, so an event equal to the last kept event gets dropped
+        let index =
+            events.partition_point(|current| rank(current, &value) != std::cmp::Ordering::Greater);
+
+        if index >= n {
+            return Pushed::REJECTED;
+        }
+
+        events.insert(index, value);
+
+        // remove the lowest ranked event
+        let dropped = events.len() > n && events.pop_back().is_some();
+
+        Pushed::stored(dropped)
+    }
+
+    /// Stores the event in the locked staged events, so the stored events don't need to be locked.
+    #[inline]
+    fn push_staged(&self, staged: &mut Box<dyn Staged<SIZE>>, value: Event<SIZE>) -> Pushed {
+        let replaced = staged.push(value);
+        self.kept.store(staged.len(), Ordering::Relaxed);
+
+        Pushed::stored(replaced)
+    }
+
+    /// Moves all staged events behind `events`. Returns the number of dropped events.
+    #[inline]
+    fn drain_staged(
+        &self,
+        staged: &Mutex<Box<dyn Staged<SIZE>>>,
+        events: &mut VecDeque<Event<SIZE>>,
+    ) -> usize {
+        let mut staged = lock_staged(staged);
+        let dropped = staged.drain_into(events);
+        self.kept.store(staged.len(), Ordering::Relaxed);
+
+        dropped
+    }
+
+    /// Calls `f` with the locked counted events, the number of kept events is updated before they are released.
+    #[inline]
+    fn with_counted<R>(
+        &self,
+        counted: &Mutex<Counted<SIZE>>,
+        f: impl FnOnce(&mut Counted<SIZE>) -> R,
+    ) -> R {
+        let mut counted = lock_counted(counted);
+        let out = f(&mut counted);
+        self.kept.store(counted.entries.len(), Ordering::Relaxed);
+
+        out
+    }
+
+    /// Returns the events kept apart from the stored events, if the slot stages events.
+    #[inline]
+    const fn staged(&self) -> Option<&Mutex<Box<dyn Staged<SIZE>>>> {
+        match &self.kind {
+            SlotKind::Inline { staged, .. } | SlotKind::LatestByKey { staged, .. } => Some(staged),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    const fn inner(&self) -> &Mutex<VecDeque<Event<SIZE>>> {
+        match &self.kind {
+            SlotKind::All(lock) | SlotKind::Last(lock) | SlotKind::First(lock) => lock,
+            SlotKind::Cmp { inner, .. }
+            | SlotKind::AllFilter { inner, .. }
+            | SlotKind::Dedup { inner, .. }
+            | SlotKind::Distinct { inner, .. }
+            | SlotKind::SortedBy { inner, .. }
+            | SlotKind::Coalesce { inner, .. }
+            | SlotKind::Top { inner, .. }
+            | SlotKind::Bottom { inner, .. }
+            | SlotKind::Sample { inner, .. }
+            | SlotKind::Max { inner, .. }
+            | SlotKind::Bounded { inner, .. }
+            | SlotKind::Reservoir { inner, .. }
+            | SlotKind::Unique { inner, .. }
+            | SlotKind::Inline { inner, .. }
+            | SlotKind::Counting { inner, .. }
+            | SlotKind::LatestByKey { inner, .. }
+            | SlotKind::Accumulate { inner, .. } => inner,
+        }
+    }
+
+    /// Locks the stored events of this slot.
+    #[inline]
+    fn lock<'a>(&'a self, lock: &'a Mutex<VecDeque<Event<SIZE>>>) -> SlotGuard<'a, SIZE> {
         // we have full controll over the lock, there should never be a panick while holding the guard
-        let mut guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
+        let events = lock.lock().unwrap_or_else(PoisonError::into_inner);
+
+        self.guard(events)
+    }
+
+    /// Wraps the locked stored events, moving all staged events behind them.
+    #[inline]
+    fn guard<'a>(
+        &'a self,
+        mut events: MutexGuard<'a, VecDeque<Event<SIZE>>>,
+    ) -> SlotGuard<'a, SIZE> {
+        if let Some(staged) = self.staged() {
+            _ = self.drain_staged(staged, &mut events);
+        }
+
+        SlotGuard {
+            events,
+            len: &self.len,
+        }
+    }
 
-        // allocate new buffer
-        let new = VecDeque::with_capacity((guard.len() / 2).min(1));
+    #[inline]
+    pub fn events(&self) -> SlotGuard<'_, SIZE> {
+        self.lock(self.inner())
+    }
+
+    /// Tries to lock stored events until the timeout is reached.
+    #[inline]
+    pub fn events_timeout(&self, timeout: Duration) -> Option<SlotGuard<'_, SIZE>> {
+        let lock = self.inner();
+        let start = Instant::now();
+
+        loop {
+            let events = match lock.try_lock() {
+                Ok(guard) => guard,
+
+                // we have full controll over the lock, there should never be a panick while holding the guard
+                Err(TryLockError::Poisoned(err)) => err.into_inner(),
+
+                Err(TryLockError::WouldBlock) => {
+                    if start.elapsed() >= timeout {
+                        return None;
+                    }
+
+                    std::thread::yield_now();
+                    continue;
+                }
+            };
+
+            return Some(self.guard(events));
+        }
+    }
+
+    /// Takes all locked stored events, leaving a buffer presized for the next batch behind.
+    #[inline]
+    pub fn events_clone_locked(&self, events: &mut VecDeque<Event<SIZE>>) -> VecDeque<Event<SIZE>> {
+        // staged events are only moved into the stored events for the query, so there is no buffer to presize
+        if self.staged().is_some() {
+            return std::mem::take(events);
+        }
+
+        // allocate new buffer, presized for the next batch, proportional to the previous one
+        let new = VecDeque::with_capacity((events.len() / 2).clamp(1, MAX_PREALLOC));
 
         // swap underlying buffer
-        std::mem::replace(&mut *guard, new)
+        std::mem::replace(events, new)
+    }
+
+    /// Takes all locked stored events together with the number of their occurrences.
+    ///
+    /// Only counting slots keep track of occurrences, events from all other slots have a count of 1.
+    #[inline]
+    pub fn take_counted_locked(
+        &self,
+        events: &mut VecDeque<Event<SIZE>>,
+    ) -> Vec<(Event<SIZE>, usize)> {
+        let SlotKind::Counting { counted, .. } = &self.kind else {
+            return std::mem::take(events)
+                .into_iter()
+                .map(|event| (event, 1))
+                .collect();
+        };
+
+        self.with_counted(counted, Counted::take)
+    }
+
+    /// Takes the accumulated value, if any event was folded into it since it was last taken.
+    ///
+    /// Returns `None` for all other slots, or if `A` is not the type of the accumulated value.
+    #[inline]
+    pub fn take_accumulated<A: 'static>(&self) -> Option<A> {
+        let SlotKind::Accumulate { acc, .. } = &self.kind else {
+            return None;
+        };
+
+        // we have full control over the lock, there should never be a panic while holding the guard
+        let mut acc = acc.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // keep the accumulated value, if it was requested with the wrong type
+        if !acc.as_ref().is_some_and(|acc| acc.is::<A>()) {
+            return None;
+        }
+
+        acc.take()
+            .and_then(|acc| acc.downcast::<A>().ok())
+            .map(|acc| *acc)
+    }
+
+    /// Removes all stored events, keeping the allocated buffer and any additional state of the slot.
+    /// Returns the number of removed events.
+    #[inline]
+    pub fn clear(&self) -> usize {
+        if let SlotKind::Counting { counted, .. } = &self.kind {
+            return self.with_counted(counted, Counted::clear);
+        }
+
+        let mut guard = self.events();
+        let cleared = guard.len();
+        guard.clear();
+
+        cleared
+    }
+
+    /// Removes all locked stored events and resets any additional state of the slot.
+    ///
+    /// The stored events stay locked until the state is reset, so new events see either the old or the reset state.
+    #[inline]
+    pub fn reset_locked(&self, events: &mut VecDeque<Event<SIZE>>) {
+        events.clear();
+
+        match &self.kind {
+            SlotKind::Counting { counted, .. } => {
+                _ = self.with_counted(counted, Counted::clear);
+            }
+            // the next event gets stored again
+            SlotKind::Sample { counter, .. } | SlotKind::Reservoir { counter, .. } => {
+                counter.store(0, Ordering::Relaxed);
+            }
+            SlotKind::Accumulate { acc, .. } => {
+                let mut acc = acc.lock().unwrap_or_else(PoisonError::into_inner);
+                *acc = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Puts previously taken events back, as if they were stored before all currently stored events.
+    /// Returns the number of dropped events.
+    ///
+    /// The events are stored again according to the slot type, so size limited, sorted and merging slots stay valid.
+    #[inline]
+    pub fn requeue_front(&self, requeued: VecDeque<Event<SIZE>>) -> usize {
+        match &self.kind {
+            // requeued events already got filtered or sampled, so they are put back as they are
+            SlotKind::All(_) | SlotKind::AllFilter { .. } | SlotKind::Sample { .. } => {
+                let mut guard = self.events();
+
+                for event in requeued.into_iter().rev() {
+                    guard.push_front(event);
+                }
+
+                0
+            }
+
+            // these slots don't keep events in order, requeued events are stored like new events
+            SlotKind::Counting { .. } | SlotKind::Accumulate { .. } => requeued
+                .into_iter()
+                .map(|event| self.push(event))
+                .filter(|pushed| pushed.dropped)
+                .count(),
+
+            SlotKind::Inline { staged, .. } | SlotKind::LatestByKey { staged, .. } => {
+                self.requeue_staged(staged, &mut self.events(), requeued)
+            }
+
+            _ => self.requeue_locked(&mut self.events(), requeued),
+        }
+    }
+
+    /// Puts requeued events in front of the locked stored events, then merges events staged in the meantime.
+    /// Returns the number of dropped events.
+    #[inline]
+    fn requeue_staged(
+        &self,
+        staged: &Mutex<Box<dyn Staged<SIZE>>>,
+        events: &mut VecDeque<Event<SIZE>>,
+        requeued: VecDeque<Event<SIZE>>,
+    ) -> usize {
+        for event in requeued.into_iter().rev() {
+            events.push_front(event);
+        }
+
+        self.drain_staged(staged, events)
+    }
+
+    /// Stores requeued events first, then stores the currently stored events again.
+    /// Returns the number of dropped events.
+    #[inline]
+    fn requeue_locked(
+        &self,
+        events: &mut VecDeque<Event<SIZE>>,
+        requeued: VecDeque<Event<SIZE>>,
+    ) -> usize {
+        let capacity = requeued.len() + events.len();
+        let stored = std::mem::replace(events, VecDeque::with_capacity(capacity));
+
+        requeued
+            .into_iter()
+            .chain(stored)
+            .map(|event| self.push_locked(events, event))
+            .filter(|pushed| pushed.dropped)
+            .count()
+    }
+
+    /// Reserves capacity for at least `additional` more events.
+    #[inline]
+    pub fn reserve(&self, additional: usize) {
+        self.events().reserve(additional);
     }
 
     /// Frees all allocated memory.
     #[inline]
     pub fn cleanup(&self) {
-        let lock = match self {
-            Self::All(lock) | Self::Last(lock) | Self::First(lock) => lock,
-            Self::Cmp { inner, cmp: _ }
-            | Self::AllFilter { inner, filter: _ }
-            | Self::Max { inner, max: _ } => inner,
-        };
+        if let SlotKind::Counting { counted, .. } = &self.kind {
+            self.with_counted(counted, |counted| *counted = Counted::default());
+        }
 
-        let mut guard = lock.lock().unwrap_or_else(PoisonError::into_inner);
-        *guard = VecDeque::new();
+        *self.events() = VecDeque::new();
     }
 }
 
 impl<const EVENT_SIZE: usize> std::fmt::Debug for Slot<EVENT_SIZE> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::All(_) => f.debug_tuple("All").finish(),
-            Self::Last(_) => f.debug_tuple("Last").finish(),
-            Self::First(_) => f.debug_tuple("First").finish(),
-            Self::Cmp { .. } => f.debug_struct("Cmp").finish(),
-            Self::AllFilter { .. } => f.debug_struct("AllFilter").finish(),
-            Self::Max { .. } => f.debug_struct("Max").finish(),
+        f.debug_struct(self.kind_name()).finish()
+    }
+}
+
+/// Locked stored events of a slot.
+///
+/// Updates the cached number of stored events of the slot, when released.
+pub struct SlotGuard<'a, const SIZE: usize> {
+    events: MutexGuard<'a, VecDeque<Event<SIZE>>>,
+    len: &'a AtomicUsize,
+}
+
+impl<const SIZE: usize> Deref for SlotGuard<'_, SIZE> {
+    type Target = VecDeque<Event<SIZE>>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.events
+    }
+}
+
+impl<const SIZE: usize> DerefMut for SlotGuard<'_, SIZE> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.events
+    }
+}
+
+impl<const SIZE: usize> Drop for SlotGuard<'_, SIZE> {
+    #[inline]
+    fn drop(&mut self) {
+        // still holding the lock, so the length matches the stored events
+        self.len.store(self.events.len(), Ordering::Relaxed);
+    }
+}
+
+impl<const SIZE: usize> std::fmt::Debug for SlotGuard<'_, SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SlotGuard")
+            .field("events", &*self.events)
+            .finish()
+    }
+}
+
+/// Returns a pseudo random number in `0..n`, advancing the splitmix64 generator `state`.
+#[inline]
+fn random_below(state: &AtomicU64, n: usize) -> usize {
+    const GOLDEN: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    let mut z = state
+        .fetch_add(GOLDEN, Ordering::Relaxed)
+        .wrapping_add(GOLDEN);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    // the remainder is smaller than n, so it always fits into usize
+    #[allow(clippy::cast_possible_truncation)]
+    let index = (z % n as u64) as usize;
+
+    index
+}
+
+/// Distinct events together with the number of their occurrences, in order of their first occurrence.
+///
+/// Lookups go through a hash index, so counting an event only compares it with events of the same hash.
+#[derive(Default)]
+pub struct Counted<const SIZE: usize> {
+    /// Positions in `entries` of all counted events with the same hash.
+    index: HashMap<u64, Vec<usize>>,
+    entries: Vec<(Event<SIZE>, usize)>,
+}
+
+impl<const SIZE: usize> Counted<SIZE> {
+    /// Counts an occurrence of `value`, `eq` decides if it matches an already counted event.
+    #[inline]
+    fn count(&mut self, hash: u64, value: Event<SIZE>, eq: &Cmp<SIZE>) {
+        let positions = self.index.entry(hash).or_default();
+
+        let counted = positions
+            .iter()
+            .copied()
+            .find(|&position| eq(&self.entries[position].0, &value));
+
+        if let Some(position) = counted {
+            self.entries[position].1 += 1;
+            return;
         }
+
+        positions.push(self.entries.len());
+        self.entries.push((value, 1));
+    }
+
+    /// Takes all counted events, leaving an empty buffer behind.
+    #[inline]
+    fn take(&mut self) -> Vec<(Event<SIZE>, usize)> {
+        self.index.clear();
+        std::mem::take(&mut self.entries)
+    }
+
+    /// Removes all counted events, keeping the allocated buffer. Returns the number of removed events.
+    #[inline]
+    fn clear(&mut self) -> usize {
+        let cleared = self.entries.len();
+
+        self.index.clear();
+        self.entries.clear();
+
+        cleared
+    }
+}
+
+/// Locks the counted events of a counting slot.
+#[inline]
+fn lock_counted<const SIZE: usize>(
+    counted: &Mutex<Counted<SIZE>>,
+) -> MutexGuard<'_, Counted<SIZE>> {
+    // we have full control over the lock, there should never be a panic while holding the guard
+    counted.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Events kept apart from the stored events, they are moved into the stored events whenever those get locked.
+///
+/// This allows slots to store events in their own layout, while queries still see a `VecDeque`.
+pub trait Staged<const SIZE: usize>: Send {
+    /// Stores the event. Returns `true`, if a previously staged event got replaced.
+    fn push(&mut self, value: Event<SIZE>) -> bool;
+
+    /// Moves all staged events behind `events`, as if they were stored after them.
+    /// Returns the number of events dropped to keep the combined events valid.
+    fn drain_into(&mut self, events: &mut VecDeque<Event<SIZE>>) -> usize;
+
+    /// Returns the number of staged events.
+    fn len(&self) -> usize;
+}
+
+/// Locks the staged events of a slot.
+#[inline]
+fn lock_staged<const SIZE: usize>(
+    staged: &Mutex<Box<dyn Staged<SIZE>>>,
+) -> MutexGuard<'_, Box<dyn Staged<SIZE>>> {
+    // we have full control over the lock, there should never be a panic while holding the guard
+    staged.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Ring buffer of the last `N` events, stored inline without allocating.
+struct Ring<const SIZE: usize, const N: usize> {
+    events: [Option<Event<SIZE>>; N],
+    /// Index of the oldest event.
+    head: usize,
+    len: usize,
+}
+
+impl<const SIZE: usize, const N: usize> Staged<SIZE> for Ring<SIZE, N> {
+    #[inline]
+    fn push(&mut self, value: Event<SIZE>) -> bool {
+        let index = (self.head + self.len) % N;
+
+        // a full ring overwrites the oldest event, which becomes the newest
+        let replaced = self.events[index].replace(value).is_some();
+        if replaced {
+            self.head = (self.head + 1) % N;
+        } else {
+            self.len += 1;
+        }
+
+        replaced
+    }
+
+    #[inline]
+    fn drain_into(&mut self, events: &mut VecDeque<Event<SIZE>>) -> usize {
+        for offset in 0..self.len {
+            if let Some(event) = self.events[(self.head + offset) % N].take() {
+                events.push_back(event);
+            }
+        }
+
+        self.head = 0;
+        self.len = 0;
+
+        // events left stored by a previous query are older, only the last N events are kept
+        let dropped = events.len().saturating_sub(N);
+        events.drain(..dropped);
+
+        dropped
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Most recent event for each key, in order of the first event with that key.
+struct Keyed<const SIZE: usize, T, K, F> {
+    key: F,
+    /// Positions in `entries` by the key of the event.
+    index: HashMap<K, usize>,
+    entries: Vec<Event<SIZE>>,
+    _t: PhantomData<fn(&T)>,
+}
+
+impl<const SIZE: usize, T, K, F> Staged<SIZE> for Keyed<SIZE, T, K, F>
+where
+    T: 'static,
+    K: Hash + Eq + Send,
+    F: Fn(&T) -> K + Send,
+{
+    #[inline]
+    fn push(&mut self, value: Event<SIZE>) -> bool {
+        let key = (self.key)(value.get_ref::<T>());
+
+        match self.index.entry(key) {
+            // replace the event with the same key, the replaced event gets dropped
+            Entry::Occupied(entry) => {
+                self.entries[*entry.get()] = value;
+                true
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(self.entries.len());
+                self.entries.push(value);
+                false
+            }
+        }
+    }
+
+    #[inline]
+    fn drain_into(&mut self, events: &mut VecDeque<Event<SIZE>>) -> usize {
+        // events left stored by a previous query are older, they keep their position, unless they get replaced
+        let dropped = if events.is_empty() {
+            0
+        } else {
+            let staged = std::mem::take(&mut self.entries);
+            self.index.clear();
+
+            events
+                .drain(..)
+                .chain(staged)
+                .map(|event| self.push(event))
+                .filter(|replaced| *replaced)
+                .count()
+        };
+
+        self.index.clear();
+        events.extend(self.entries.drain(..));
+
+        dropped
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.entries.len()
     }
 }
 
-#[derive(Debug, Clone, Copy)]
 /// Specifies what events get stored.
 pub enum SlotType<T: 'static> {
     /// All events of the matching type get stored.
     All,
 
-    /// Only the last event of the matching type gets stored.
-    Last,
+    /// Only the last event of the matching type gets stored.
+    Last,
+
+    /// Only the first event of the matching type gets stored.
+    First,
+
+    /// A user specified function gets called to decide if the new event replaces the currently stored event.
+    /// Return `true`, if the current event should get replaced. Else return `false`.
+    Cmp(fn(current: &T, new: &T) -> bool),
+
+    /// A user specified function gets called to decide if the new event should be kept.
+    /// Return `true` if the event should be kept, else return `false` if the event should be discarded.
+    AllFilter(fn(new: &T) -> bool),
+
+    /// Like [`SlotType::Cmp`], but accepts a closure that can capture state.
+    /// Return `true`, if the current event should get replaced. Else return `false`.
+    #[doc(alias = "CmpClosure")]
+    #[allow(clippy::type_complexity)]
+    CmpFn(Box<dyn Fn(&T, &T) -> bool + Send + Sync>),
+
+    /// Like [`SlotType::AllFilter`], but accepts a closure that can capture state.
+    /// Return `true` if the event should be kept, else return `false` if the event should be discarded.
+    #[doc(alias = "AllFilterClosure")]
+    FilterFn(Box<dyn Fn(&T) -> bool + Send + Sync>),
+
+    /// All events get stored, except events equal to the most recently stored event.
+    /// The user specified function gets called to compare the stored and the new event, usually `PartialEq::eq`.
+    Dedup(fn(last: &T, new: &T) -> bool),
+
+    /// All events get stored, except events equal to any currently stored event.
+    /// The user specified function gets called to compare the stored and the new event, usually `PartialEq::eq`.
+    ///
+    /// Each new event is compared with every stored event, so this is best suited for a small number of distinct events.
+    /// After the stored events got queried, previously stored events can be stored again.
+    Distinct(fn(current: &T, new: &T) -> bool),
+
+    /// All events get stored, sorted by the user specified function instead of by arrival.
+    /// Events that compare equal keep their arrival order.
+    SortedBy(fn(a: &T, b: &T) -> std::cmp::Ordering),
 
-    /// Only the first event of the matching type gets stored.
-    First,
+    /// Only a single event gets stored, new events get merged into it by the user specified function.
+    /// The first event after the stored event got queried is stored as it is.
+    Coalesce(fn(acc: &mut T, new: &T)),
 
-    /// A user specified function gets called to decide if the new event replaces the currently stored event.
-    /// Return `true`, if the current event should get replaced. Else return `false`.
-    Cmp(fn(current: &T, new: &T) -> bool),
+    /// Like [`SlotType::Coalesce`], but accepts a closure that can capture state.
+    #[allow(clippy::type_complexity)]
+    CoalesceFn(Box<dyn Fn(&mut T, &T) + Send + Sync>),
 
-    /// A user specified function gets called to decide if the new event should be kept.
-    /// Return `true` if the event should be kept, else return `false` if the event should be discarded.
-    AllFilter(fn(new: &T) -> bool),
+    /// Only the n largest events get stored, compared by the user specified function.
+    /// Stored events are ordered from the largest to the smallest event.
+    Top(usize, fn(a: &T, b: &T) -> std::cmp::Ordering),
+
+    /// Only the n smallest events get stored, compared by the user specified function.
+    /// Stored events are ordered from the smallest to the largest event.
+    Bottom(usize, fn(a: &T, b: &T) -> std::cmp::Ordering),
+
+    /// Only every nth event gets stored, starting with the first event.
+    Sample(usize),
+
+    /// A uniformly random sample of up to k events gets stored, every event is equally likely to be kept.
+    ///
+    /// Sampling starts over, once the stored events got queried.
+    Reservoir(usize),
+
+    /// Like [`SlotType::Reservoir`], but the random number generator uses the given seed,
+    /// so the same events always produce the same sample.
+    ReservoirSeeded(usize, u64),
 
     /// Collect all events until number is reached.
     ///
     /// Any more events replace the oldest events.
     Max(usize),
+
+    /// Collect all events until number is reached.
+    ///
+    /// Any more events get dropped, until the stored events got queried. Unlike [`SlotType::Max`], the stored events
+    /// are never replaced, dropped events are counted by [`EventBackend::dropped_count`](crate::EventBackend::dropped_count).
+    Bounded(usize),
+}
+
+impl<T: 'static> std::fmt::Debug for SlotType<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => f.write_str("All"),
+            Self::Last => f.write_str("Last"),
+            Self::First => f.write_str("First"),
+            Self::Cmp(_) => f.write_str("Cmp"),
+            Self::AllFilter(_) => f.write_str("AllFilter"),
+            Self::CmpFn(_) => f.write_str("CmpFn"),
+            Self::FilterFn(_) => f.write_str("FilterFn"),
+            Self::Dedup(_) => f.write_str("Dedup"),
+            Self::Distinct(_) => f.write_str("Distinct"),
+            Self::SortedBy(_) => f.write_str("SortedBy"),
+            Self::Coalesce(_) => f.write_str("Coalesce"),
+            Self::CoalesceFn(_) => f.write_str("CoalesceFn"),
+            Self::Top(n, _) => f.debug_tuple("Top").field(n).finish(),
+            Self::Bottom(n, _) => f.debug_tuple("Bottom").field(n).finish(),
+            Self::Sample(n) => f.debug_tuple("Sample").field(n).finish(),
+            Self::Reservoir(k) => f.debug_tuple("Reservoir").field(k).finish(),
+            Self::ReservoirSeeded(k, seed) => f
+                .debug_tuple("ReservoirSeeded")
+                .field(k)
+                .field(seed)
+                .finish(),
+            Self::Max(max) => f.debug_tuple("Max").field(max).finish(),
+            Self::Bounded(capacity) => f.debug_tuple("Bounded").field(capacity).finish(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -254,6 +1554,60 @@ mod tests {
         assert_eq!(values, (0..100).collect::<Vec<_>>());
     }
 
+    #[test]
+    fn test_slot_push_all() {
+        let all = Slot::<16>::new::<u32>(SlotType::All);
+        let max = Slot::<16>::new::<u32>(SlotType::Max(4));
+        let sample = Slot::<16>::new::<u32>(SlotType::Sample(3));
+
+        assert_eq!(all.push_all((0..10u32).map(Event::new).collect()), 0);
+        assert_eq!(max.push_all((0..10u32).map(Event::new).collect()), 6);
+
+        // skipped events count as dropped
+        assert_eq!(sample.push_all((0..10u32).map(Event::new).collect()), 6);
+
+        assert_eq!(all.len(), 10);
+        assert_eq!(max.len(), 4);
+        assert_eq!(
+            std::mem::take(&mut *sample.events())
+                .into_iter()
+                .map(Event::get::<u32>)
+                .collect::<Vec<_>>(),
+            [0, 3, 6, 9]
+        );
+    }
+
+    #[test]
+    fn test_slot_events_clone() {
+        let slot = Slot::<16>::new::<u32>(SlotType::All);
+
+        for i in 0..1000u32 {
+            slot.push(Event::new(i));
+        }
+
+        let events = slot.events_clone_locked(&mut slot.events());
+
+        assert_eq!(
+            events
+                .into_iter()
+                .map(Event::get::<u32>)
+                .collect::<Vec<_>>(),
+            (0..1000).collect::<Vec<_>>()
+        );
+
+        // the new buffer is sized for the next batch
+        assert_eq!(slot.len(), 0);
+        assert!(slot.events().capacity() >= 500);
+    }
+
+    #[test]
+    fn test_slot_reserve() {
+        let slot = Slot::<16>::new::<u32>(SlotType::All);
+
+        slot.reserve(4096);
+        assert!(slot.events().capacity() >= 4096);
+    }
+
     #[test]
     fn test_slot_first() {
         let slot = Slot::<16>::new::<u32>(SlotType::First);
@@ -333,6 +1687,287 @@ mod tests {
         assert_eq!(values.len(), 50);
     }
 
+    #[test]
+    fn test_slot_dedup() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Dedup(u32::eq));
+
+        for i in [1u32, 1, 2, 2, 2, 3, 1, 1] {
+            slot.push(Event::new(i));
+        }
+
+        let mut values = Vec::with_capacity(4);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        assert_eq!(values, [1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_slot_dedup_runs() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Dedup(u32::eq));
+
+        for _ in 0..100 {
+            slot.push(Event::new(7u32));
+        }
+
+        assert_eq!(slot.events().len(), 1);
+
+        // a run of other events after the stored event
+        for i in 0..100u32 {
+            slot.push(Event::new(if i < 50 { 7u32 } else { 8 }));
+        }
+
+        let mut values = Vec::with_capacity(2);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        assert_eq!(values, [7, 8]);
+    }
+
+    #[test]
+    fn test_slot_distinct() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Distinct(u32::eq));
+
+        for i in 0..200u32 {
+            slot.push(Event::new(i % 10));
+        }
+
+        let mut values = Vec::with_capacity(10);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        assert_eq!(values, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_slot_sorted_by() {
+        let slot = Slot::<16>::new::<u32>(SlotType::SortedBy(u32::cmp));
+
+        // simple pseudo random values
+        let mut state = 0x2545_f491_u32;
+        for _ in 0..100 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            slot.push(Event::new(state));
+        }
+
+        let mut values = Vec::with_capacity(100);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        assert_eq!(values.len(), 100);
+        assert!(values.is_sorted());
+    }
+
+    #[test]
+    fn test_slot_top_bottom() {
+        let top = Slot::<16>::new::<u32>(SlotType::Top(5, u32::cmp));
+        let bottom = Slot::<16>::new::<u32>(SlotType::Bottom(5, u32::cmp));
+
+        // simple pseudo random values
+        let mut values = Vec::with_capacity(1000);
+        let mut state = 0x2545_f491_u32;
+        for _ in 0..1000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            values.push(state);
+
+            top.push(Event::new(state));
+            bottom.push(Event::new(state));
+        }
+
+        values.sort_unstable();
+
+        let stored = std::mem::take(&mut *top.events())
+            .into_iter()
+            .map(Event::get::<u32>)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stored,
+            values.iter().rev().take(5).copied().collect::<Vec<_>>()
+        );
+
+        let stored = std::mem::take(&mut *bottom.events())
+            .into_iter()
+            .map(Event::get::<u32>)
+            .collect::<Vec<_>>();
+        assert_eq!(stored, values[..5]);
+    }
+
+    #[test]
+    fn test_slot_sample() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Sample(10));
+
+        for i in 0..100u32 {
+            slot.push(Event::new(i));
+        }
+
+        let mut values = Vec::with_capacity(10);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        assert_eq!(values, (0..100).step_by(10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_slot_bounded() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Bounded(4));
+
+        let dropped = (0..10u32)
+            .map(|i| slot.push(Event::new(i)))
+            .filter(|pushed| pushed.dropped)
+            .count();
+        assert_eq!(dropped, 6);
+
+        // the first events are kept
+        let values = std::mem::take(&mut *slot.events())
+            .into_iter()
+            .map(Event::get::<u32>)
+            .collect::<Vec<_>>();
+        assert_eq!(values, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_slot_reservoir() {
+        let sample = |seed| {
+            let slot = Slot::<16>::new::<u32>(SlotType::ReservoirSeeded(10, seed));
+
+            for i in 0..100u32 {
+                slot.push(Event::new(i));
+            }
+
+            let events = std::mem::take(&mut *slot.events());
+
+            events
+                .into_iter()
+                .map(Event::get::<u32>)
+                .collect::<Vec<_>>()
+        };
+
+        // the same seed produces the same sample
+        assert_eq!(sample(7), sample(7));
+        assert_eq!(sample(7).len(), 10);
+
+        // every event is kept with probability 10 / 100
+        let mut kept = [0u32; 100];
+        for seed in 0..2000 {
+            for i in sample(seed) {
+                kept[i as usize] += 1;
+            }
+        }
+
+        for count in kept {
+            assert!((120..280).contains(&count), "{count}");
+        }
+    }
+
+    #[test]
+    fn test_slot_reservoir_refill() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Reservoir(4));
+
+        for i in 0..100u32 {
+            slot.push(Event::new(i));
+        }
+        assert_eq!(std::mem::take(&mut *slot.events()).len(), 4);
+
+        // a queried reservoir fills up again
+        for i in 0..3u32 {
+            slot.push(Event::new(i));
+        }
+        assert_eq!(slot.len(), 3);
+    }
+
+    #[test]
+    fn test_slot_coalesce() {
+        let slot = Slot::<16>::new::<u32>(SlotType::Coalesce(|acc, new| *acc += new));
+
+        for i in 1..=100u32 {
+            slot.push(Event::new(i));
+        }
+
+        let events = std::mem::take(&mut *slot.events());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.into_iter().next().unwrap().get::<u32>(), 5050);
+    }
+
+    #[test]
+    fn test_slot_coalesce_fn() {
+        let scale = 2;
+        let slot =
+            Slot::<16>::new::<(i32, i32)>(SlotType::CoalesceFn(Box::new(move |acc, new| {
+                acc.0 += scale * new.0;
+                acc.1 += scale * new.1;
+            })));
+
+        for delta in [(1, 0), (0, 1), (-1, 2)] {
+            slot.push(Event::new(delta));
+        }
+
+        let events = std::mem::take(&mut *slot.events());
+        assert_eq!(events.len(), 1);
+
+        // the first event is stored as it is
+        assert_eq!(
+            events.into_iter().next().unwrap().get::<(i32, i32)>(),
+            (-1, 6)
+        );
+    }
+
+    #[test]
+    fn test_slot_cmp_fn() {
+        let factor = 2;
+        let slot = Slot::<16>::new::<u32>(SlotType::CmpFn(Box::new(move |current, next| {
+            *next > factor * current
+        })));
+
+        for i in 0..100u32 {
+            slot.push(Event::new(i));
+        }
+
+        let stored = {
+            let mut query = slot.events();
+            assert_eq!(query.len(), 1);
+            query.pop_front().unwrap()
+        };
+        assert_eq!(stored.get::<u32>(), 63);
+    }
+
+    #[test]
+    fn test_slot_filter_fn() {
+        let min = 50;
+        let slot = Slot::<16>::new::<u32>(SlotType::FilterFn(Box::new(move |next| *next >= min)));
+
+        for i in 0..100u32 {
+            slot.push(Event::new(i));
+        }
+
+        let mut values = Vec::with_capacity(50);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        assert_eq!(values, (50..100).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_slot_max() {
         let slot = Slot::<16>::new::<u32>(SlotType::Max(100));
@@ -351,4 +1986,173 @@ mod tests {
         assert_eq!(values, (100..200).collect::<Vec<_>>());
         assert_eq!(values.len(), 100);
     }
+
+    #[test]
+    fn test_slot_max_small() {
+        for (max, expected) in [(0, &[][..]), (1, &[9][..]), (2, &[8, 9][..])] {
+            let slot = Slot::<16>::new::<u32>(SlotType::Max(max));
+
+            // initial capacity is at least one event
+            assert!(slot.events().capacity() >= 1);
+
+            for i in 0..10u32 {
+                slot.push(Event::new(i));
+            }
+
+            let mut values = Vec::with_capacity(2);
+
+            let mut query = slot.events();
+            while let Some(e) = query.pop_front() {
+                values.push(e.get::<u32>());
+            }
+
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    fn test_slot_unique() {
+        let slot = Slot::<16>::unique::<u32>(3);
+
+        for i in [1, 2, 3, 1, 4, 3u32] {
+            slot.push(Event::new(i));
+        }
+
+        let mut values = Vec::with_capacity(3);
+
+        let mut query = slot.events();
+        while let Some(e) = query.pop_front() {
+            values.push(e.get::<u32>());
+        }
+
+        // pushing 1 again moved it to the most recent position, so 2 got evicted by 4
+        assert_eq!(values, [1, 4, 3]);
+    }
+
+    #[test]
+    fn test_slot_inline() {
+        let slot = Slot::<16>::inline::<4>();
+
+        for i in 0..10u32 {
+            slot.push(Event::new(i));
+        }
+
+        assert_eq!(slot.len(), 4);
+
+        let values = std::mem::take(&mut *slot.events())
+            .into_iter()
+            .map(Event::get::<u32>)
+            .collect::<Vec<_>>();
+
+        // oldest events got overwritten
+        assert_eq!(values, [6, 7, 8, 9]);
+
+        for i in 10..13u32 {
+            slot.push(Event::new(i));
+        }
+
+        // keep the remaining events stored
+        {
+            let mut query = slot.events();
+            assert_eq!(query.pop_front().map(Event::get::<u32>), Some(10));
+        }
+
+        for i in 13..16u32 {
+            slot.push(Event::new(i));
+        }
+
+        let values = std::mem::take(&mut *slot.events())
+            .into_iter()
+            .map(Event::get::<u32>)
+            .collect::<Vec<_>>();
+
+        // remaining events are older than new events, and still count against the capacity
+        assert_eq!(values, [12, 13, 14, 15]);
+        assert_eq!(slot.len(), 0);
+    }
+
+    #[test]
+    fn test_slot_counting() {
+        let slot = Slot::<16>::counting::<char>();
+
+        for c in ['a', 'a', 'b', 'a', 'c', 'b'] {
+            slot.push(Event::new(c));
+        }
+
+        let counts = slot
+            .take_counted_locked(&mut slot.events())
+            .into_iter()
+            .map(|(e, count)| (e.get::<char>(), count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(counts, [('a', 3), ('b', 2), ('c', 1)]);
+        assert!(slot.take_counted_locked(&mut slot.events()).is_empty());
+    }
+
+    #[test]
+    fn test_slot_latest_by_key() {
+        let slot = Slot::<16>::latest_by_key::<(u32, f32), _>(|(id, _)| *id);
+
+        for reading in [(1u32, 0.5f32), (2, 1.0), (1, 0.75), (3, 2.0), (2, 1.5)] {
+            slot.push(Event::new(reading));
+        }
+
+        let values = std::mem::take(&mut *slot.events())
+            .into_iter()
+            .map(Event::get::<(u32, f32)>)
+            .collect::<Vec<_>>();
+
+        // keys keep the position of their first event
+        assert_eq!(values, [(1, 0.75), (2, 1.5), (3, 2.0)]);
+
+        for reading in [(1u32, 0.5f32), (2, 1.0)] {
+            slot.push(Event::new(reading));
+        }
+
+        // keep the remaining events stored
+        {
+            let mut query = slot.events();
+            assert_eq!(
+                query.pop_back().map(Event::get::<(u32, f32)>),
+                Some((2, 1.0))
+            );
+        }
+
+        for reading in [(3u32, 2.0f32), (1, 0.25)] {
+            slot.push(Event::new(reading));
+        }
+
+        let values = std::mem::take(&mut *slot.events())
+            .into_iter()
+            .map(Event::get::<(u32, f32)>)
+            .collect::<Vec<_>>();
+
+        // remaining events are replaced by newer events with the same key
+        assert_eq!(values, [(1, 0.25), (3, 2.0)]);
+    }
+
+    #[test]
+    fn test_slot_accumulate() {
+        let slot = Slot::<16>::accumulate::<u32, u64>(0, |sum, value| *sum += u64::from(*value));
+
+        // nothing got folded yet
+        assert_eq!(slot.take_accumulated::<u64>(), None);
+
+        for i in 1..=4u32 {
+            slot.push(Event::new(i));
+        }
+
+        // events are not stored
+        assert_eq!(slot.len(), 0);
+
+        // wrong type keeps the accumulated value
+        assert_eq!(slot.take_accumulated::<u32>(), None);
+
+        assert_eq!(slot.take_accumulated::<u64>(), Some(10));
+        assert_eq!(slot.take_accumulated::<u64>(), None);
+
+        // folding starts again from the initial value
+        slot.push(Event::new(5u32));
+        assert_eq!(slot.take_accumulated::<u64>(), Some(5));
+    }
 }
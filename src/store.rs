@@ -0,0 +1,97 @@
+use std::{any::TypeId, marker::PhantomData};
+
+use crate::{
+    err::{EventError, Value},
+    query::{Query, UnblockingQuery},
+    EventBackend, DEFAULT_EVENT_SIZE,
+};
+
+/// Typed handle to an event type, registered to store events with [`EventBackend::subscribe_store`].
+///
+/// Allows triggering and querying events without naming the event type again.
+pub struct StoreSubscription<T: 'static, const EVENT_SIZE: usize = DEFAULT_EVENT_SIZE> {
+    type_id: TypeId,
+
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static, const EVENT_SIZE: usize> StoreSubscription<T, EVENT_SIZE> {
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            _t: PhantomData,
+        }
+    }
+
+    /// Returns the id of the event type.
+    #[inline]
+    #[must_use]
+    pub const fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Triggers a new event. See [`EventBackend::new_event`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if the event type is not registered with `backend`.
+    #[inline]
+    pub fn new_event(
+        &self,
+        backend: &EventBackend<EVENT_SIZE>,
+        value: T,
+    ) -> Result<(), EventError<T, Value>> {
+        backend.new_event(value)
+    }
+
+    /// Returns an iterator over each stored event. See [`EventBackend::query`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if the event type is not registered with `backend`.
+    #[inline]
+    pub fn query(
+        &self,
+        backend: &EventBackend<EVENT_SIZE>,
+    ) -> Result<UnblockingQuery<T, EVENT_SIZE>, EventError<T>> {
+        backend.query()
+    }
+
+    /// Returns an iterator over each stored event, that holds the lock on the stored events. See [`EventBackend::query_blocking`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if the event type is not registered with `backend`.
+    #[inline]
+    pub fn query_blocking<'a>(
+        &self,
+        backend: &'a EventBackend<EVENT_SIZE>,
+    ) -> Result<Query<'a, T, EVENT_SIZE>, EventError<T>> {
+        backend.query_blocking()
+    }
+
+    /// Returns the number of stored events. See [`EventBackend::len_for`].
+    ///
+    /// # Errors
+    /// Returns an `EventError`, if the event type is not registered with `backend`.
+    #[inline]
+    pub fn len(&self, backend: &EventBackend<EVENT_SIZE>) -> Result<usize, EventError<T>> {
+        backend.len_for()
+    }
+}
+
+impl<T: 'static, const EVENT_SIZE: usize> Clone for StoreSubscription<T, EVENT_SIZE> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static, const EVENT_SIZE: usize> Copy for StoreSubscription<T, EVENT_SIZE> {}
+
+impl<T: 'static, const EVENT_SIZE: usize> std::fmt::Debug for StoreSubscription<T, EVENT_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoreSubscription")
+            .field("type_id", &self.type_id)
+            .field("type_name", &std::any::type_name::<T>())
+            .finish()
+    }
+}
@@ -0,0 +1,40 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Mutex, PoisonError},
+};
+
+/// A boxed future, as returned by async event listeners.
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Queue of futures, created by async event listeners, that wait to get spawned.
+#[derive(Default)]
+pub struct TaskQueue {
+    inner: Mutex<Vec<BoxFuture<'static, ()>>>,
+}
+
+impl TaskQueue {
+    #[inline]
+    pub fn push(&self, task: BoxFuture<'static, ()>) {
+        // we have full control over the lock, there should never be a panic while holding the guard
+        let mut guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        guard.push(task);
+    }
+
+    #[inline]
+    pub fn take(&self) -> Vec<BoxFuture<'static, ()>> {
+        // we have full control over the lock, there should never be a panic while holding the guard
+        let mut guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        std::mem::take(&mut *guard)
+    }
+}
+
+impl std::fmt::Debug for TaskQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+
+        f.debug_struct("TaskQueue")
+            .field("tasks", &guard.len())
+            .finish()
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "async")]
+
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use eventsys::{BoxFuture, EventBackend};
+
+fn block_on(mut task: BoxFuture<'static, ()>) {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    while task.as_mut().poll(&mut cx) == Poll::Pending {}
+}
+
+#[test]
+fn test_async_listener() {
+    let mut system = EventBackend::default();
+
+    let state = Arc::new(Mutex::new(Vec::<u32>::new()));
+    // Setup async event listener
+    {
+        let state_c = state.clone();
+        let listener = move |event: u32| -> BoxFuture<'static, ()> {
+            let state_c = state_c.clone();
+            Box::pin(async move {
+                state_c.lock().unwrap().push(event);
+            })
+        };
+        system.register_async_listener::<u32>(listener).unwrap();
+    }
+
+    // call events
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u32>(2).unwrap();
+
+    // futures did not run yet
+    assert!(state.lock().unwrap().is_empty());
+
+    let tasks = system.take_tasks();
+    assert_eq!(tasks.len(), 2);
+
+    for task in tasks {
+        block_on(task);
+    }
+
+    assert_eq!(&*state.lock().unwrap(), &[1, 2]);
+    assert!(system.take_tasks().is_empty());
+}
@@ -1,4 +1,11 @@
-use eventsys::{EventBackend, SlotType};
+use std::{
+    any::TypeId,
+    panic::AssertUnwindSafe,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use eventsys::{EventBackend, EventMerge, SlotType};
 
 #[test]
 fn test_batch() {
@@ -80,3 +87,1105 @@ fn test_batch() {
     // check filter event
     assert_eq!(&filter_events, &[51, 999]);
 }
+
+#[test]
+fn test_batch_blocking_panic() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..10u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    // consumer panics in the middle of the batch
+    let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        for event in system.query_blocking::<u32>().unwrap() {
+            assert!(event < 4, "failed to process event");
+        }
+    }));
+    assert!(res.is_err());
+
+    // events after the failed one survived
+    let remaining = system.query_blocking::<u32>().unwrap().collect::<Vec<_>>();
+    assert_eq!(remaining, (5..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_batch_take_slot() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..100u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let events = system.take_slot::<u32>().unwrap();
+    assert_eq!(events, (0..100).collect::<Vec<_>>());
+
+    // store is empty, but still usable
+    assert_eq!(system.query::<u32>().unwrap().len(), 0);
+
+    system.new_event::<u32>(100).unwrap();
+    assert_eq!(system.take_slot::<u32>().unwrap(), [100]);
+}
+
+#[test]
+fn test_batch_process_within() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::All).unwrap();
+
+    for i in 0..10u32 {
+        system.new_event::<u32>(i).unwrap();
+        system.new_event::<u64>(u64::from(i)).unwrap();
+    }
+
+    // slow processing, budget runs out before all events are processed
+    let budget = Duration::from_millis(50);
+    let start = Instant::now();
+    let processed = system.process_all_within(budget, |_, _| {
+        std::thread::sleep(Duration::from_millis(10));
+    });
+    let elapsed = start.elapsed();
+
+    assert!(processed > 0);
+    assert!(processed < 20);
+    assert!(elapsed >= budget);
+    assert!(elapsed < budget * 4);
+
+    // remaining events are still stored in order
+    let small = system.query::<u32>().unwrap().collect::<Vec<_>>();
+    let big = system.query::<u64>().unwrap().collect::<Vec<_>>();
+
+    assert_eq!(small.len() + big.len(), 20 - processed);
+    assert_eq!(big, (0..10).collect::<Vec<_>>());
+    assert_eq!(small, (processed as u32..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_batch_process_within_requeue() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::Max(2)).unwrap();
+    system
+        .register_store::<u64>(SlotType::SortedBy(u64::cmp))
+        .unwrap();
+
+    // new events arrive while processing, then the budget runs out
+    let budget = Duration::from_millis(20);
+
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u32>(2).unwrap();
+
+    let processed = system.process_all_within(budget, |_, _| {
+        system.new_event::<u32>(3).unwrap();
+        system.new_event::<u32>(4).unwrap();
+        std::thread::sleep(budget);
+    });
+    assert_eq!(processed, 1);
+
+    // the requeued event is older than the new events, so it gets replaced
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 1);
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [3, 4]);
+
+    for i in [5, 1, 3u64] {
+        system.new_event::<u64>(i).unwrap();
+    }
+
+    let processed = system.process_all_within(budget, |_, _| {
+        system.new_event::<u64>(4).unwrap();
+        system.new_event::<u64>(2).unwrap();
+        std::thread::sleep(budget);
+    });
+    assert_eq!(processed, 1);
+
+    // requeued events are sorted together with the new events
+    assert_eq!(
+        system.query::<u64>().unwrap().collect::<Vec<_>>(),
+        [2, 3, 4, 5]
+    );
+}
+
+#[test]
+fn test_batch_process_within_panic() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..5u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        system.process_all_within(Duration::from_secs(10), |_, event| {
+            assert_ne!(*event.get_ref::<u32>(), 2);
+        })
+    }));
+    assert!(result.is_err());
+
+    // the panicking event is dropped, remaining events are still stored
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [3, 4]);
+}
+
+#[test]
+fn test_batch_dropped_count() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::Max(10)).unwrap();
+
+    for i in 0..15u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    // overflowing events replaced the oldest ones
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 5);
+
+    // querying resets the drop count
+    let events = system.query::<u32>().unwrap().collect::<Vec<_>>();
+    assert_eq!(events, (5..15).collect::<Vec<_>>());
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 0);
+}
+
+#[test]
+fn test_batch_flush_on_drop() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::All).unwrap();
+
+    let flushed = Arc::new(Mutex::new(Vec::<u32>::new()));
+    {
+        let flushed_c = flushed.clone();
+        system
+            .set_flush_on_drop::<u32>(move |events| {
+                flushed_c.lock().unwrap().extend(events);
+            })
+            .unwrap();
+    }
+
+    for i in 0..10u32 {
+        system.new_event::<u32>(i).unwrap();
+        system.new_event::<u64>(u64::from(i)).unwrap();
+    }
+
+    // consumed events don't get flushed
+    assert_eq!(system.query::<u32>().unwrap().take(2).count(), 2);
+
+    system.new_event::<u32>(10).unwrap();
+    system.new_event::<u32>(11).unwrap();
+
+    assert!(flushed.lock().unwrap().is_empty());
+
+    drop(system);
+
+    assert_eq!(&*flushed.lock().unwrap(), &[10, 11]);
+}
+
+#[test]
+fn test_batch_front() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    assert_eq!(system.front::<u32>().unwrap(), None);
+
+    for i in 0..10u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    assert_eq!(system.front::<u32>().unwrap(), Some(0));
+    assert_eq!(system.front::<u32>().unwrap(), Some(0));
+
+    // buffer is unchanged
+    let events = system.query::<u32>().unwrap().collect::<Vec<_>>();
+    assert_eq!(events, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_batch_fold() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u64>(SlotType::All).unwrap();
+
+    for i in 1..=100u64 {
+        system.new_event::<u64>(i).unwrap();
+    }
+
+    let sum = system
+        .fold::<u64, u64>(0, |sum, event| sum + event)
+        .unwrap();
+    assert_eq!(sum, 5050);
+
+    // events got consumed
+    let sum = system
+        .fold::<u64, u64>(0, |sum, event| sum + event)
+        .unwrap();
+    assert_eq!(sum, 0);
+}
+
+#[test]
+fn test_batch_mapped() {
+    struct RawInput {
+        key: char,
+        pressed: bool,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Jump;
+
+    let mut system = EventBackend::default();
+
+    system.register_store::<char>(SlotType::All).unwrap();
+    system.register_store::<Jump>(SlotType::All).unwrap();
+
+    let inputs = [
+        RawInput {
+            key: 'a',
+            pressed: true,
+        },
+        RawInput {
+            key: ' ',
+            pressed: true,
+        },
+        RawInput {
+            key: ' ',
+            pressed: false,
+        },
+    ];
+
+    // derive two event types from each input
+    for input in &inputs {
+        system.new_event_mapped(input, |input| input.key).unwrap();
+
+        if input.key == ' ' && input.pressed {
+            system.new_event_mapped(input, |_| Jump).unwrap();
+        }
+    }
+
+    let keys = system.query::<char>().unwrap().collect::<Vec<_>>();
+    let jumps = system.query::<Jump>().unwrap().collect::<Vec<_>>();
+
+    assert_eq!(keys, ['a', ' ', ' ']);
+    assert_eq!(jumps, [Jump]);
+}
+
+#[test]
+fn test_batch_shared() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..10u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let events = system.query_shared::<u32>().unwrap();
+
+    // multiple consumers read the same batch
+    let consumers = (0..4)
+        .map(|_| {
+            let events = events.clone();
+            std::thread::spawn(move || events.iter().sum::<u32>())
+        })
+        .collect::<Vec<_>>();
+
+    for consumer in consumers {
+        assert_eq!(consumer.join().unwrap(), 45);
+    }
+
+    assert_eq!(&*events, (0..10).collect::<Vec<_>>());
+
+    // store got emptied once
+    assert!(system.query_shared::<u32>().unwrap().is_empty());
+}
+
+#[test]
+fn test_batch_counting() {
+    let mut system = EventBackend::default();
+
+    system.register_store_counting::<char>().unwrap();
+
+    for c in ['a', 'a', 'b', 'a', 'c', 'b'] {
+        system.new_event(c).unwrap();
+    }
+
+    assert_eq!(system.len_for::<char>().unwrap(), 3);
+
+    // counted events are not stored events
+    assert_eq!(system.query::<char>().unwrap().len(), 0);
+
+    assert_eq!(
+        system.query_counts::<char>().unwrap(),
+        [('a', 3), ('b', 2), ('c', 1)]
+    );
+    assert!(system.query_counts::<char>().unwrap().is_empty());
+
+    system.new_event('a').unwrap();
+    assert_eq!(system.clear::<char>().unwrap(), 1);
+    assert!(system.query_counts::<char>().unwrap().is_empty());
+}
+
+#[test]
+fn test_batch_reset() {
+    let mut system = EventBackend::default();
+
+    system.register_store_counting::<char>().unwrap();
+    system.register_store::<u32>(SlotType::Max(2)).unwrap();
+
+    for c in ['a', 'a', 'b'] {
+        system.new_event(c).unwrap();
+    }
+
+    for i in 0..5u32 {
+        system.new_event(i).unwrap();
+    }
+
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 3);
+
+    system.reset::<char>().unwrap();
+    system.reset::<u32>().unwrap();
+
+    // counts start over
+    system.new_event('a').unwrap();
+    assert_eq!(system.query_counts::<char>().unwrap(), [('a', 1)]);
+
+    // stored events and drop count are gone
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 0);
+    assert_eq!(system.query::<u32>().unwrap().len(), 0);
+}
+
+#[test]
+fn test_batch_clear() {
+    let mut system = EventBackend::default();
+
+    let seen = Arc::new(Mutex::new(0));
+    let seen_c = Arc::clone(&seen);
+
+    system.register_store::<u32>(SlotType::Max(2)).unwrap();
+    system
+        .register_listener::<u32>(move |_: &u32| *seen_c.lock().unwrap() += 1)
+        .unwrap();
+    system.register_listener::<u64>(|_: &u64| {}).unwrap();
+
+    system.new_events::<u32>(0..5).unwrap();
+    assert_eq!(system.clear::<u32>().unwrap(), 2);
+    assert_eq!(system.clear::<u32>().unwrap(), 0);
+
+    // stored events are gone, drop count is kept
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 3);
+
+    // listeners and store stay registered
+    system.new_event::<u32>(7).unwrap();
+    assert_eq!(*seen.lock().unwrap(), 6);
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [7]);
+
+    assert!(system.clear::<u64>().is_err());
+    assert!(system.clear::<u16>().is_err());
+}
+
+#[test]
+fn test_batch_clear_all() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::Last).unwrap();
+    system.register_listener::<u16>(|_: &u16| {}).unwrap();
+
+    system.new_events::<u32>(0..5).unwrap();
+    system.new_events::<u64>(0..5).unwrap();
+
+    let cleared = system.clear_all();
+
+    // only types with a store are reported
+    assert_eq!(cleared.len(), 2);
+    assert_eq!(cleared[&TypeId::of::<u32>()], 5);
+    assert_eq!(cleared[&TypeId::of::<u64>()], 1);
+
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    assert_eq!(system.len_for::<u64>().unwrap(), 0);
+}
+
+#[test]
+fn test_batch_reserve() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_listener::<u64>(|_: &u64| {}).unwrap();
+
+    system.reserve::<u32>(1024).unwrap();
+
+    // listener only registrations have no store to reserve
+    assert!(system.reserve::<u64>(1024).is_err());
+    assert!(system.reserve::<u16>(1024).is_err());
+}
+
+#[test]
+fn test_batch_blocking_timeout() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+
+    let timeout = Duration::from_millis(20);
+
+    {
+        // hold access to the event type
+        let _query = system.query_blocking::<u32>().unwrap();
+
+        let start = Instant::now();
+        let res = system.query_blocking_timeout::<u32>(timeout).unwrap();
+
+        assert!(res.is_none());
+        assert!(start.elapsed() >= timeout);
+    }
+
+    system.new_event::<u32>(2).unwrap();
+
+    let query = system
+        .query_blocking_timeout::<u32>(timeout)
+        .unwrap()
+        .unwrap();
+    assert_eq!(query.collect::<Vec<_>>(), [2]);
+}
+
+#[test]
+fn test_batch_drain_set() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::All).unwrap();
+    system.register_listener::<u8>(|_| {}).unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u32>(2).unwrap();
+    system.new_event::<u64>(3).unwrap();
+
+    let (a, b): (Vec<u32>, Vec<u64>) = system.drain_set::<(u32, u64)>().unwrap();
+    assert_eq!(a, [1, 2]);
+    assert_eq!(b, [3]);
+
+    system.new_event::<u32>(4).unwrap();
+
+    // u8 has no store, nothing gets drained
+    assert!(system.drain_set::<(u32, u8)>().is_err());
+    assert!(system.drain_set::<(u32, i8)>().is_err());
+
+    let (a,) = system.drain_set::<(u32,)>().unwrap();
+    assert_eq!(a, [4]);
+}
+
+#[test]
+fn test_batch_process_retry() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..6u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let errors = system
+        .process_retry::<u32, u32>(|event| if event % 2 == 0 { Ok(()) } else { Err(*event) })
+        .unwrap();
+    assert_eq!(errors, [1, 3, 5]);
+
+    system.new_event::<u32>(6).unwrap();
+
+    // failed events stay in order, ahead of new events
+    let query = system.query::<u32>().unwrap();
+    assert_eq!(query.collect::<Vec<_>>(), [1, 3, 5, 6]);
+
+    assert!(system.process_retry::<u8, ()>(|_| Ok(())).is_err());
+}
+
+#[test]
+fn test_batch_process_retry_bounded() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::Max(2)).unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u32>(2).unwrap();
+
+    // new events fill the store while processing, all events fail
+    let errors = system
+        .process_retry::<u32, u32>(|event| {
+            if *event == 1 {
+                system.new_event::<u32>(3).unwrap();
+                system.new_event::<u32>(4).unwrap();
+            }
+
+            Err(*event)
+        })
+        .unwrap();
+    assert_eq!(errors, [1, 2]);
+
+    // failed events are older than the new events, so they get replaced
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 2);
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [3, 4]);
+}
+
+#[test]
+fn test_batch_process_retry_panic() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..6u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        system.process_retry::<u32, u32>(|event| {
+            assert_ne!(*event, 3);
+
+            if event % 2 == 0 {
+                Ok(())
+            } else {
+                Err(*event)
+            }
+        })
+    }));
+    assert!(result.is_err());
+
+    // the panicking event is dropped, failed events stay in front of the remaining events
+    assert_eq!(
+        system.query::<u32>().unwrap().collect::<Vec<_>>(),
+        [1, 4, 5]
+    );
+}
+
+#[test]
+fn test_batch_max_evict() {
+    let mut system = EventBackend::default();
+
+    let evicted = Arc::new(Mutex::new(Vec::new()));
+    let evicted_clone = Arc::clone(&evicted);
+
+    system
+        .register_store_max_cb::<u32>(3, move |event| {
+            evicted_clone.lock().unwrap().push(event);
+        })
+        .unwrap();
+
+    for i in 0..6u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    assert_eq!(*evicted.lock().unwrap(), [0, 1, 2]);
+
+    let query = system.query::<u32>().unwrap();
+    assert_eq!(query.collect::<Vec<_>>(), [3, 4, 5]);
+
+    assert!(system.register_store_max_cb::<u64>(0, |_| {}).is_err());
+}
+
+#[test]
+fn test_batch_drain_mapped_all() {
+    #[derive(Debug, PartialEq)]
+    enum Merged {
+        A(u32),
+        B(u64),
+    }
+
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::All).unwrap();
+
+    let merge = EventMerge::new()
+        .with::<u32>(Merged::A)
+        .with::<u64>(Merged::B);
+
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u64>(2).unwrap();
+    system.new_event::<u32>(3).unwrap();
+
+    let events = system.drain_mapped_all(&merge).unwrap();
+    assert_eq!(events, [Merged::A(1), Merged::A(3), Merged::B(2)]);
+
+    // everything got drained
+    assert!(system.drain_mapped_all(&merge).unwrap().is_empty());
+
+    // unregistered types leave other stores untouched
+    system.new_event::<u32>(4).unwrap();
+
+    let merge = EventMerge::new()
+        .with::<u32>(Merged::A)
+        .with::<u8>(|b| Merged::B(u64::from(b)));
+    assert!(system.drain_mapped_all(&merge).is_err());
+
+    let query = system.query::<u32>().unwrap();
+    assert_eq!(query.collect::<Vec<_>>(), [4]);
+}
+
+#[test]
+fn test_batch_len_for() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::Max(2)).unwrap();
+    system.register_listener::<u8>(|_| {}).unwrap();
+
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    assert!(system.pending_types().is_empty());
+
+    for i in 0..4 {
+        system.new_event::<u32>(i).unwrap();
+        system.new_event::<u64>(u64::from(i)).unwrap();
+    }
+
+    assert_eq!(system.len_for::<u32>().unwrap(), 4);
+    assert_eq!(system.len_for::<u64>().unwrap(), 2);
+    assert_eq!(
+        system.pending_types(),
+        [TypeId::of::<u32>(), TypeId::of::<u64>()]
+    );
+
+    // partially consumed query
+    {
+        let mut query = system.query_blocking::<u64>().unwrap();
+        assert_eq!(query.next(), Some(2));
+    }
+    assert_eq!(system.len_for::<u64>().unwrap(), 0);
+
+    _ = system.take_slot::<u32>().unwrap();
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    assert!(system.pending_types().is_empty());
+
+    assert!(system.len_for::<u8>().is_err());
+    assert!(system.len_for::<i8>().is_err());
+}
+
+#[test]
+fn test_batch_indexed() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 10..15u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let indexed = system.query_indexed::<u32>().unwrap().collect::<Vec<_>>();
+    assert_eq!(indexed, [(0, 10), (1, 11), (2, 12), (3, 13), (4, 14)]);
+
+    // indices start at 0 for every batch
+    system.new_event::<u32>(15).unwrap();
+
+    let indexed = system.query_indexed::<u32>().unwrap().collect::<Vec<_>>();
+    assert_eq!(indexed, [(0, 15)]);
+}
+
+#[test]
+fn test_batch_peek() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..3u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    for _ in 0..2 {
+        let peek = system.query_peek::<u32>().unwrap();
+
+        let iter = peek.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.copied().collect::<Vec<_>>(), [0, 1, 2]);
+        assert!(!peek.is_empty());
+    }
+
+    // peeking did not consume events
+    let query = system.query::<u32>().unwrap();
+    assert_eq!(query.collect::<Vec<_>>(), [0, 1, 2]);
+
+    assert!(system.query_peek::<u32>().unwrap().is_empty());
+}
+
+#[test]
+fn test_batch_exact_size() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    assert!(system.query::<u32>().unwrap().is_empty());
+
+    for i in 0..4u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let mut query = system.query::<u32>().unwrap();
+    assert!(!query.is_empty());
+    assert_eq!(ExactSizeIterator::len(&query), 4);
+
+    assert_eq!(query.next(), Some(0));
+    assert_eq!(query.size_hint(), (3, Some(3)));
+
+    let mut values = Vec::with_capacity(query.len());
+    values.extend(query);
+    assert_eq!(values, [1, 2, 3]);
+
+    system.new_event::<u32>(4).unwrap();
+
+    let query = system.query_blocking::<u32>().unwrap();
+    assert_eq!(ExactSizeIterator::len(&query), 1);
+    assert!(!query.is_empty());
+}
+
+#[test]
+fn test_batch_rev() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    for i in 0..5u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let mut query = system.query::<u32>().unwrap();
+    assert_eq!(query.next_back(), Some(4));
+    assert_eq!(query.next(), Some(0));
+    assert_eq!(query.rev().collect::<Vec<_>>(), [3, 2, 1]);
+
+    for i in 0..5u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    let mut query = system.query_blocking::<u32>().unwrap();
+    assert_eq!(query.rfind(|event| event % 2 == 1), Some(3));
+    assert_eq!(query.next_back(), Some(2));
+    drop(query);
+
+    // remaining events got cleared with the query
+    assert_eq!(system.query::<u32>().unwrap().len(), 0);
+}
+
+#[test]
+fn test_batch_rev_counting() {
+    let mut system = EventBackend::default();
+
+    system.register_store_counting::<char>().unwrap();
+
+    for c in ['a', 'a', 'b', 'a', 'c', 'b'] {
+        system.new_event(c).unwrap();
+    }
+
+    // counted events can't be taken from the back, so no count gets lost
+    let mut query = system.query_blocking::<char>().unwrap();
+    assert_eq!(query.next_back(), None);
+    drop(query);
+
+    assert_eq!(
+        system.query_counts::<char>().unwrap(),
+        [('a', 3), ('b', 2), ('c', 1)]
+    );
+}
+
+#[test]
+fn test_batch_peek_cloned() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<String>(SlotType::All).unwrap();
+
+    system.new_event::<String>("a".to_owned()).unwrap();
+    system.new_event::<String>("b".to_owned()).unwrap();
+
+    assert_eq!(system.peek::<String>().unwrap(), ["a", "b"]);
+    assert_eq!(system.peek::<String>().unwrap(), ["a", "b"]);
+
+    // peeking did not consume events
+    assert_eq!(
+        system.query::<String>().unwrap().collect::<Vec<_>>(),
+        ["a", "b"]
+    );
+    assert!(system.peek::<String>().unwrap().is_empty());
+
+    assert!(system.peek::<u8>().is_err());
+}
+
+#[test]
+fn test_batch_new_events() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    // all dispatched events are counted
+    assert_eq!(system.new_events::<u32>(0..4).unwrap(), 4);
+    assert_eq!(system.new_events::<u32>([]).unwrap(), 0);
+    assert_eq!(
+        system.query::<u32>().unwrap().collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+
+    // the error carries the first value
+    let err = system.new_events::<u64>([5, 6, 7]).unwrap_err();
+    assert_eq!(err.into_inner(), 5);
+
+    assert!(system.new_events::<u64>([]).is_ok());
+
+    // bounded slots still drop events, when storing all at once
+    system.register_store::<u16>(SlotType::Max(2)).unwrap();
+    assert_eq!(system.new_events::<u16>(0..5).unwrap(), 5);
+
+    assert_eq!(system.dropped_count::<u16>().unwrap(), 3);
+    assert_eq!(system.query::<u16>().unwrap().len(), 2);
+}
+
+#[test]
+fn test_batch_min_max_by() {
+    let mut system = EventBackend::default();
+
+    system
+        .register_store_min_by::<u32, _>(|event| *event)
+        .unwrap();
+    system
+        .register_store_max_by::<(u64, char), _>(|(priority, _)| *priority)
+        .unwrap();
+
+    for i in (0..100u32).chain((0..100).rev()) {
+        system.new_event::<u32>(i).unwrap();
+    }
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [0]);
+
+    for i in 0..100u64 {
+        system.new_event::<(u64, char)>((i, 'a')).unwrap();
+    }
+    // ties keep the stored event
+    system.new_event::<(u64, char)>((99, 'b')).unwrap();
+
+    assert_eq!(
+        system.query::<(u64, char)>().unwrap().collect::<Vec<_>>(),
+        [(99, 'a')]
+    );
+}
+
+#[test]
+fn test_batch_retain() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.new_events::<u32>(0..10).unwrap();
+
+    {
+        let mut query = system.query_blocking::<u32>().unwrap();
+        query.retain(|event| event % 2 == 0);
+        assert_eq!(query.len(), 5);
+    }
+
+    // retained events stay stored
+    let mut query = system.query::<u32>().unwrap();
+    assert_eq!(query.len(), 5);
+
+    query.retain(|event| *event > 4);
+    assert_eq!(query.collect::<Vec<_>>(), [6, 8]);
+
+    // the unblocking query already took all events
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+}
+
+#[test]
+fn test_batch_retain_counting() {
+    let mut system = EventBackend::default();
+
+    system.register_store_counting::<char>().unwrap();
+
+    for c in ['a', 'a', 'b', 'a', 'c', 'b'] {
+        system.new_event(c).unwrap();
+    }
+
+    // retaining only sees stored events, counted events and their counts are left as they are
+    let mut query = system.query_blocking::<char>().unwrap();
+    query.retain(|event| *event != 'b');
+    assert!(query.is_empty());
+    drop(query);
+
+    assert_eq!(
+        system.query_counts::<char>().unwrap(),
+        [('a', 3), ('b', 2), ('c', 1)]
+    );
+}
+
+#[test]
+fn test_batch_inline() {
+    let mut system = EventBackend::default();
+
+    system.register_store_inline::<u32, 4>().unwrap();
+
+    for i in 0..6 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    // overwritten events count as dropped
+    assert_eq!(system.len_for::<u32>().unwrap(), 4);
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 2);
+    assert_eq!(
+        system.query::<u32>().unwrap().collect::<Vec<_>>(),
+        [2, 3, 4, 5]
+    );
+
+    // storing starts again after the query
+    system.new_event::<u32>(6).unwrap();
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [6]);
+    assert!(system.query::<u32>().unwrap().next().is_none());
+}
+
+#[test]
+fn test_batch_latest_by_key() {
+    #[derive(Debug, PartialEq)]
+    struct SensorReading {
+        sensor_id: u32,
+        value: f32,
+    }
+
+    let mut system = EventBackend::default();
+
+    system
+        .register_store_latest_by_key::<SensorReading, _>(|reading| reading.sensor_id)
+        .unwrap();
+
+    for (sensor_id, value) in [(1, 0.5), (2, 1.0), (1, 0.75)] {
+        system
+            .new_event(SensorReading { sensor_id, value })
+            .unwrap();
+    }
+
+    // replaced readings count as dropped
+    assert_eq!(system.dropped_count::<SensorReading>().unwrap(), 1);
+    assert_eq!(
+        system.query::<SensorReading>().unwrap().collect::<Vec<_>>(),
+        [
+            SensorReading {
+                sensor_id: 1,
+                value: 0.75
+            },
+            SensorReading {
+                sensor_id: 2,
+                value: 1.0
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_batch_accumulate() {
+    let mut system = EventBackend::default();
+
+    system
+        .register_store_accumulate::<u32, (u32, u32)>((0, 0), |(count, max), value| {
+            *count += 1;
+            *max = (*max).max(*value);
+        })
+        .unwrap();
+
+    assert_eq!(system.query_accumulated::<u32, (u32, u32)>().unwrap(), None);
+
+    system.new_events::<u32>([3, 9, 4]).unwrap();
+
+    // events are folded, not stored
+    assert_eq!(system.query::<u32>().unwrap().len(), 0);
+
+    assert_eq!(
+        system.query_accumulated::<u32, (u32, u32)>().unwrap(),
+        Some((3, 9))
+    );
+    assert_eq!(system.query_accumulated::<u32, (u32, u32)>().unwrap(), None);
+
+    system.register_store::<u64>(SlotType::All).unwrap();
+    system.new_event::<u64>(1).unwrap();
+
+    // other slots have nothing accumulated
+    assert_eq!(system.query_accumulated::<u64, u64>().unwrap(), None);
+    assert!(system.query_accumulated::<u16, u16>().is_err());
+}
+
+#[test]
+fn test_batch_drain() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.new_events::<u32>(0..100).unwrap();
+
+    let query = system.drain::<u32>().unwrap();
+
+    // the slot is emptied before the query is consumed
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+    assert_eq!(query.collect::<Vec<_>>(), (0..100).collect::<Vec<_>>());
+
+    system.new_event::<u32>(7).unwrap();
+    assert_eq!(system.drain::<u32>().unwrap().collect::<Vec<_>>(), [7]);
+    assert!(system.drain::<u32>().unwrap().is_empty());
+
+    assert!(system.drain::<u64>().is_err());
+}
+
+#[test]
+fn test_batch_new_events_listeners() {
+    let mut system = EventBackend::default();
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_c = Arc::clone(&seen);
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system
+        .register_listener::<u32>(move |event: &u32| seen_c.lock().unwrap().push(*event))
+        .unwrap();
+
+    assert_eq!(system.new_events::<u32>(0..100).unwrap(), 100);
+
+    // listeners are called for every event
+    assert_eq!(*seen.lock().unwrap(), (0..100).collect::<Vec<_>>());
+    assert_eq!(
+        system.query::<u32>().unwrap().collect::<Vec<_>>(),
+        (0..100).collect::<Vec<_>>()
+    );
+
+    // disabled events are not dispatched
+    system.disable::<u32>().unwrap();
+    assert_eq!(system.new_events::<u32>(0..10).unwrap(), 0);
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+}
+
+#[test]
+fn test_batch_try_new_event() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    assert!(system.try_new_event::<u32>(1));
+    assert!(system.try_new_event::<u32>(2));
+    assert!(!system.try_new_event::<u64>(3));
+    assert!(!system.try_new_event::<[u64; 64]>([0; 64]));
+
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [1, 2]);
+}
+
+#[test]
+fn test_batch_bounded() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::Bounded(2)).unwrap();
+    assert!(system.register_store::<u64>(SlotType::Bounded(0)).is_err());
+
+    assert!(system.new_event_report::<u32>(1).unwrap().stored);
+    assert!(system.new_event_report::<u32>(2).unwrap().stored);
+
+    // a full store rejects new events
+    let outcome = system.new_event_report::<u32>(3).unwrap();
+    assert!(!outcome.stored);
+    assert!(outcome.dropped_by_slot);
+
+    system.new_event::<u32>(4).unwrap();
+    assert_eq!(system.dropped_count::<u32>().unwrap(), 2);
+
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [1, 2]);
+
+    // querying makes room again
+    system.new_event::<u32>(5).unwrap();
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [5]);
+}
@@ -1,3 +1,8 @@
+use std::{
+    sync::{Arc, RwLock},
+    thread,
+};
+
 use eventsys::{EventBackend, SlotType};
 
 #[test]
@@ -51,3 +56,128 @@ fn test_disable_enable() {
     // check single events
     assert_eq!(&single_events, &[123, 456]);
 }
+
+#[test]
+fn test_disable_all_concurrent_register() {
+    struct Many<const N: usize>;
+
+    fn register<const N: usize>(system: &RwLock<EventBackend>) {
+        let mut system = system.write().unwrap();
+        system.register_store::<Many<N>>(SlotType::All).unwrap();
+    }
+
+    let system = Arc::new(RwLock::new(EventBackend::default()));
+
+    let registering = {
+        let system = Arc::clone(&system);
+
+        thread::spawn(move || {
+            for _ in 0..100 {
+                register::<0>(&system);
+                register::<1>(&system);
+                register::<2>(&system);
+                register::<3>(&system);
+            }
+        })
+    };
+
+    let disabling = {
+        let system = Arc::clone(&system);
+
+        thread::spawn(move || {
+            for _ in 0..1000 {
+                system.read().unwrap().disable_all();
+            }
+        })
+    };
+
+    registering.join().unwrap();
+    disabling.join().unwrap();
+
+    let system = system.read().unwrap();
+    system.disable_all();
+
+    // all types got registered and are disabled
+    system.new_event(Many::<0>).unwrap();
+    system.new_event(Many::<3>).unwrap();
+    assert_eq!(system.query::<Many<0>>().unwrap().len(), 0);
+    assert_eq!(system.query::<Many<3>>().unwrap().len(), 0);
+}
+
+#[test]
+fn test_pause_resume() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(RwLock::new(0));
+    let calls_c = Arc::clone(&calls);
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_store::<u64>(SlotType::All).unwrap();
+    system
+        .register_listener::<u32>(move |_: &u32| *calls_c.write().unwrap() += 1)
+        .unwrap();
+
+    system.disable::<u64>().unwrap();
+    system.pause();
+    assert!(system.is_paused());
+
+    // events are still checked while paused
+    system.new_event::<u32>(1).unwrap();
+    system.new_events::<u32>([2, 3]).unwrap();
+    system.new_event_deferred::<u32>(4).unwrap();
+    assert!(system.new_event::<u16>(5).is_err());
+
+    assert_eq!(*calls.read().unwrap(), 0);
+    assert_eq!(system.len_for::<u32>().unwrap(), 0);
+
+    system.resume();
+    assert!(!system.is_paused());
+
+    system.new_event::<u32>(6).unwrap();
+    system.new_event::<u64>(7).unwrap();
+
+    assert_eq!(*calls.read().unwrap(), 1);
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [6]);
+
+    // per type flags stay independent
+    assert_eq!(system.len_for::<u64>().unwrap(), 0);
+    assert!(system.is_enabled::<u32>().unwrap());
+    assert!(!system.is_enabled::<u64>().unwrap());
+}
+
+#[test]
+fn test_is_enabled() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    assert!(system.is_enabled::<u32>().unwrap());
+
+    system.disable::<u32>().unwrap();
+    assert!(!system.is_enabled::<u32>().unwrap());
+
+    system.enable_all();
+    assert!(system.is_enabled::<u32>().unwrap());
+
+    assert!(system.is_enabled::<u64>().is_err());
+}
+
+#[test]
+fn test_scoped_disable() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    {
+        let _guard = system.scoped_disable::<u32>().unwrap();
+
+        system.new_event::<u32>(1).unwrap();
+        assert!(!system.is_enabled::<u32>().unwrap());
+    }
+
+    // dropping the guard enables the event again
+    system.new_event::<u32>(2).unwrap();
+    assert!(system.is_enabled::<u32>().unwrap());
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [2]);
+
+    assert!(system.scoped_disable::<u64>().is_err());
+}
@@ -1,6 +1,12 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    any::TypeId,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
 
-use eventsys::EventBackend;
+use eventsys::{EventBackend, EventOutcome, ListenerFlow, SlotType};
 
 #[test]
 fn test_listeners() {
@@ -76,3 +82,579 @@ fn test_listeners_multiple() {
 
     assert_eq!(unboxed, &[11, 21, 12, 22]);
 }
+
+#[test]
+fn test_listeners_deferred() {
+    let mut system = EventBackend::default();
+
+    let state = Arc::new(Mutex::new(Vec::<u32>::new()));
+    // Setup single event listener
+    {
+        let state_c = state.clone();
+        let listener = move |event: &u32| {
+            state_c.lock().unwrap().push(*event);
+        };
+        system.register_listener::<u32>(listener).unwrap();
+    }
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    // store events without calling listeners
+    system.new_event_deferred::<u32>(1).unwrap();
+    system.new_event_deferred::<u32>(2).unwrap();
+
+    assert!(state.lock().unwrap().is_empty());
+
+    // call listeners for stored events
+    let dispatched = system.dispatch_stored();
+    assert_eq!(dispatched, 2);
+
+    assert_eq!(&*state.lock().unwrap(), &[1, 2]);
+
+    // dispatched events are consumed
+    assert_eq!(system.query::<u32>().unwrap().len(), 0);
+    assert_eq!(system.dispatch_stored(), 0);
+}
+
+#[test]
+fn test_listeners_report() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::First).unwrap();
+    system.register_listener::<u32>(|_| {}).unwrap();
+    system.register_listener::<u32>(|_| {}).unwrap();
+
+    system
+        .register_store::<u8>(SlotType::AllFilter(|new| *new > 10))
+        .unwrap();
+
+    system.register_listener::<u16>(|_| {}).unwrap();
+
+    let outcome = system.new_event_report::<u32>(1).unwrap();
+    assert_eq!(
+        outcome,
+        EventOutcome {
+            listeners_invoked: 2,
+            listeners_panicked: 0,
+            stored: true,
+            dropped_by_slot: false
+        }
+    );
+
+    // first event is already stored
+    let outcome = system.new_event_report::<u32>(2).unwrap();
+    assert_eq!(
+        outcome,
+        EventOutcome {
+            listeners_invoked: 2,
+            listeners_panicked: 0,
+            stored: false,
+            dropped_by_slot: true
+        }
+    );
+
+    // rejected by filter
+    let outcome = system.new_event_report::<u8>(5).unwrap();
+    assert!(!outcome.stored && outcome.dropped_by_slot);
+    assert_eq!(outcome.listeners_invoked, 0);
+
+    let outcome = system.new_event_report::<u8>(50).unwrap();
+    assert!(outcome.stored && !outcome.dropped_by_slot);
+
+    // only listeners
+    let outcome = system.new_event_report::<u16>(1).unwrap();
+    assert_eq!(
+        outcome,
+        EventOutcome {
+            listeners_invoked: 1,
+            listeners_panicked: 0,
+            stored: false,
+            dropped_by_slot: false
+        }
+    );
+
+    // disabled types are not processed
+    system.disable::<u16>().unwrap();
+    assert_eq!(
+        system.new_event_report::<u16>(1).unwrap(),
+        EventOutcome::default()
+    );
+
+    assert!(system.new_event_report::<i8>(1).is_err());
+}
+
+#[test]
+fn test_listeners_named() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |_| calls_clone.lock().unwrap().push("other"))
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    let count = system
+        .register_named_listener::<u32>("on_click", move |_| {
+            calls_clone.lock().unwrap().push("old");
+        })
+        .unwrap();
+    assert_eq!(count, 2);
+
+    system.new_event::<u32>(1).unwrap();
+    assert_eq!(*calls.lock().unwrap(), ["other", "old"]);
+    calls.lock().unwrap().clear();
+
+    let calls_clone = Arc::clone(&calls);
+    let replaced = system
+        .replace_named_listener::<u32>("on_click", move |_| {
+            calls_clone.lock().unwrap().push("new");
+        })
+        .unwrap();
+    assert!(replaced);
+
+    system.new_event::<u32>(1).unwrap();
+    assert_eq!(*calls.lock().unwrap(), ["other", "new"]);
+    calls.lock().unwrap().clear();
+
+    // replacing an unknown name registers it
+    let calls_clone = Arc::clone(&calls);
+    let replaced = system
+        .replace_named_listener::<u32>("on_hover", move |_| {
+            calls_clone.lock().unwrap().push("hover");
+        })
+        .unwrap();
+    assert!(!replaced);
+
+    system.new_event::<u32>(1).unwrap();
+    assert_eq!(*calls.lock().unwrap(), ["other", "new", "hover"]);
+}
+
+#[test]
+fn test_listeners_once() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    let handle = system
+        .register_listener_once::<u32>(move |event| calls_clone.lock().unwrap().push(*event))
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    let cancelled = system
+        .register_listener_once::<u32>(move |_| calls_clone.lock().unwrap().push(0))
+        .unwrap();
+
+    assert!(cancelled.cancel());
+    assert!(!cancelled.cancel());
+    assert!(!handle.is_done());
+
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u32>(2).unwrap();
+
+    // only called for the first event, the cancelled listener never got called
+    assert_eq!(*calls.lock().unwrap(), [1]);
+    assert!(handle.is_done());
+    assert!(!handle.cancel());
+}
+
+#[test]
+fn test_listeners_once_concurrent() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let calls_clone = Arc::clone(&calls);
+    let handle = system
+        .register_listener_once::<u32>(move |_| {
+            calls_clone.fetch_add(1, Ordering::Relaxed);
+        })
+        .unwrap();
+
+    let system = &system;
+    std::thread::scope(|s| {
+        for t in 0..8u32 {
+            s.spawn(move || {
+                for i in 0..100 {
+                    system.new_event::<u32>(t * 100 + i).unwrap();
+                }
+            });
+        }
+    });
+
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert!(handle.is_done());
+}
+
+#[test]
+fn test_listeners_remove() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    let first = system
+        .register_listener_handle::<u32>(move |_| calls_clone.lock().unwrap().push(1))
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    let second = system
+        .register_listener_handle::<u32>(move |_| calls_clone.lock().unwrap().push(2))
+        .unwrap();
+
+    let other = system.register_listener_handle::<u64>(|_| {}).unwrap();
+
+    assert!(system.remove_listener::<u32>(first).unwrap());
+    assert!(!system.remove_listener::<u32>(first).unwrap());
+
+    // ids of other event types are ignored
+    assert!(!system.remove_listener::<u32>(other).unwrap());
+
+    system.new_event::<u32>(0).unwrap();
+    assert_eq!(*calls.lock().unwrap(), [2]);
+
+    assert!(system.remove_listener::<u32>(second).unwrap());
+    assert!(system.remove_listener::<i8>(second).is_err());
+}
+
+#[test]
+fn test_listeners_count() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    assert_eq!(system.listener_count::<u32>().unwrap(), 0);
+
+    let first = system.register_listener_handle::<u32>(|_| {}).unwrap();
+    system.register_listener_once::<u32>(|_| {}).unwrap();
+    let subscription = system.subscribe::<u32>(|_| {}).unwrap();
+    assert_eq!(system.listener_count::<u32>().unwrap(), 3);
+
+    // one-shot listeners only count until they got called
+    system.new_event::<u32>(0).unwrap();
+    assert_eq!(system.listener_count::<u32>().unwrap(), 2);
+
+    drop(subscription);
+    assert!(system.remove_listener::<u32>(first).unwrap());
+    assert_eq!(system.listener_count::<u32>().unwrap(), 0);
+
+    assert!(system.listener_count::<u64>().is_err());
+}
+
+#[test]
+fn test_listeners_remove_stable_ids() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let mut ids = Vec::new();
+    for i in 0..4 {
+        let calls_clone = Arc::clone(&calls);
+        let id = system
+            .register_listener_handle::<u32>(move |_| calls_clone.lock().unwrap().push(i))
+            .unwrap();
+        ids.push(id);
+    }
+
+    // removing a listener does not change the ids of other listeners
+    assert!(system.remove_listener::<u32>(ids[1]).unwrap());
+    assert!(system.remove_listener::<u32>(ids[3]).unwrap());
+
+    // removing an already removed id is a no-op
+    assert!(!system.remove_listener::<u32>(ids[1]).unwrap());
+
+    system.new_event::<u32>(0).unwrap();
+    assert_eq!(*calls.lock().unwrap(), [0, 2]);
+}
+
+#[test]
+fn test_listeners_subscribe() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    let subscription = system
+        .subscribe::<u32>(move |event| calls_clone.lock().unwrap().push(*event))
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .subscribe::<u32>(move |event| calls_clone.lock().unwrap().push(event + 100))
+        .unwrap()
+        .forget();
+
+    assert_eq!(
+        system.new_event_report::<u32>(1).unwrap().listeners_invoked,
+        2
+    );
+
+    drop(subscription);
+
+    assert_eq!(
+        system.new_event_report::<u32>(2).unwrap().listeners_invoked,
+        1
+    );
+    assert_eq!(*calls.lock().unwrap(), [1, 101, 102]);
+
+    // dropping a subscription after the backend is gone
+    let subscription = system.subscribe::<u32>(|_| {}).unwrap();
+    drop(system);
+    drop(subscription);
+}
+
+#[test]
+fn test_listeners_mut() {
+    let mut system = EventBackend::default();
+
+    let sum = Arc::new(Mutex::new(0));
+    let sum_clone = Arc::clone(&sum);
+
+    let mut local = 0;
+    system
+        .register_listener_mut::<u32>(move |event| {
+            local += event;
+            *sum_clone.lock().unwrap() = local;
+        })
+        .unwrap();
+
+    for i in 1..=4 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    assert_eq!(*sum.lock().unwrap(), 10);
+}
+
+#[test]
+fn test_listeners_ctl() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener_ctl::<u32>(move |event| {
+            calls_clone.lock().unwrap().push(*event);
+
+            if *event == 2 {
+                ListenerFlow::Remove
+            } else {
+                ListenerFlow::Keep
+            }
+        })
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |event| calls_clone.lock().unwrap().push(*event * 10))
+        .unwrap();
+
+    for i in 1..=3u32 {
+        system.new_event::<u32>(i).unwrap();
+    }
+
+    // listeners after the removed one still got the second event
+    assert_eq!(*calls.lock().unwrap(), [1, 10, 2, 20, 30]);
+}
+
+#[test]
+fn test_listeners_priority() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    for (priority, name) in [(0, "a"), (10, "b"), (-5, "c"), (10, "d")] {
+        let calls_clone = Arc::clone(&calls);
+        system
+            .register_listener_with_priority::<u32>(priority, move |_| {
+                calls_clone.lock().unwrap().push(name);
+            })
+            .unwrap();
+    }
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |_| calls_clone.lock().unwrap().push("e"))
+        .unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+
+    // descending priority, ties keep registration order
+    assert_eq!(*calls.lock().unwrap(), ["b", "d", "a", "e", "c"]);
+}
+
+#[test]
+fn test_listeners_global() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    system.register_global_listener(move |id, event| {
+        let call = if id == TypeId::of::<u32>() {
+            format!("global u32 {}", event.get_ref::<u32>())
+        } else {
+            "global other".to_owned()
+        };
+
+        calls_clone.lock().unwrap().push(call);
+    });
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |event| {
+            calls_clone.lock().unwrap().push(format!("u32 {event}"));
+        })
+        .unwrap();
+    system.register_store::<u8>(SlotType::All).unwrap();
+
+    let outcome = system.new_event_report::<u32>(1).unwrap();
+    assert_eq!(outcome.listeners_invoked, 2);
+
+    system.new_event::<u8>(2).unwrap();
+
+    // unregistered types are not passed to global listeners
+    assert!(system.new_event::<u16>(3).is_err());
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["global u32 1", "u32 1", "global other"]
+    );
+}
+
+#[test]
+fn test_listeners_filtering() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener_with_priority::<u32>(10, move |event| {
+            calls_clone.lock().unwrap().push(("high", *event));
+        })
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener_filtering::<u32>(move |event| {
+            calls_clone.lock().unwrap().push(("filter", *event));
+            *event <= 10
+        })
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |event| calls_clone.lock().unwrap().push(("low", *event)))
+        .unwrap();
+
+    let outcome = system.new_event_report::<u32>(1).unwrap();
+    assert_eq!(outcome.listeners_invoked, 2);
+    system.new_event::<u32>(20).unwrap();
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [
+            ("high", 1),
+            ("filter", 1),
+            ("high", 20),
+            ("filter", 20),
+            ("low", 20)
+        ]
+    );
+
+    // consumed events still get stored
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [1, 20]);
+}
+
+#[test]
+fn test_listeners_filtering_priority() {
+    let mut system = EventBackend::default();
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    // registered first, but with a lower priority
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |event| {
+            calls_clone.lock().unwrap().push(("gameplay", *event))
+        })
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener_filtering_with_priority::<u32>(10, move |event| {
+            calls_clone.lock().unwrap().push(("ui", *event));
+            true
+        })
+        .unwrap();
+
+    let calls_clone = Arc::clone(&calls);
+    _ = system
+        .register_listener_once::<u32>(move |event| {
+            calls_clone.lock().unwrap().push(("once", *event))
+        })
+        .unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+
+    // the ui consumed the event, gameplay never sees it, one-shot listeners still do
+    assert_eq!(*calls.lock().unwrap(), [("ui", 1), ("once", 1)]);
+}
+
+#[test]
+fn test_listeners_panic_hook() {
+    let mut system = EventBackend::default();
+
+    let panics = Arc::new(Mutex::new(Vec::new()));
+
+    let panics_clone = Arc::clone(&panics);
+    system.set_panic_hook(move |id, payload| {
+        let message = payload.downcast_ref::<&str>().copied().unwrap_or_default();
+        panics_clone.lock().unwrap().push((id, message));
+
+        // panics of the hook itself are caught as well
+        panic!("hook");
+    });
+
+    system
+        .register_listener::<u32>(|_| panic!("listener"))
+        .unwrap();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    system
+        .register_listener::<u32>(move |_| {
+            calls_clone.fetch_add(1, Ordering::Relaxed);
+        })
+        .unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+
+    assert_eq!(*panics.lock().unwrap(), [(TypeId::of::<u32>(), "listener")]);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_listeners_counted() {
+    let mut system = EventBackend::default();
+
+    system.register_listener::<u32>(|_| {}).unwrap();
+    system.register_listener::<u32>(|_| {}).unwrap();
+
+    assert_eq!(system.new_event_counted::<u32>(1).unwrap(), 2);
+
+    // panicking listeners are not counted
+    system.register_listener::<u32>(|_| panic!()).unwrap();
+    assert_eq!(system.new_event_counted::<u32>(2).unwrap(), 2);
+
+    let outcome = system.new_event_report::<u32>(3).unwrap();
+    assert_eq!(outcome.listeners_invoked, 3);
+    assert_eq!(outcome.listeners_panicked, 1);
+
+    system.disable::<u32>().unwrap();
+    assert_eq!(system.new_event_counted::<u32>(4).unwrap(), 0);
+}
@@ -0,0 +1,234 @@
+use std::any::TypeId;
+
+use eventsys::{EventBackend, EventBackendBuilder, EventDescriptor, SlotType, TypeDiagnostics};
+
+#[test]
+fn test_bulk_register() {
+    let mut system = EventBackend::default();
+
+    let descriptors = [
+        EventDescriptor::new(|system: &mut EventBackend| {
+            system.register_store::<u32>(SlotType::All)?;
+            Ok(())
+        }),
+        EventDescriptor::new(|system: &mut EventBackend| {
+            system.register_store::<u64>(SlotType::Last)?;
+            system.register_listener::<u64>(|_: &u64| {})?;
+            Ok(())
+        }),
+        EventDescriptor::new(|system: &mut EventBackend| {
+            system.register_store::<[u8; 1024]>(SlotType::All)?;
+            Ok(())
+        }),
+    ];
+
+    let results = system.bulk_register(&descriptors);
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_ok());
+
+    // event type is too big
+    assert!(results[2].is_err());
+
+    system.new_event::<u32>(1).unwrap();
+    system.new_event::<u64>(2).unwrap();
+
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), [1]);
+    assert_eq!(system.query::<u64>().unwrap().collect::<Vec<_>>(), [2]);
+}
+
+#[test]
+fn test_register_invalid_param() {
+    let mut system = EventBackend::default();
+
+    let err = system.register_store::<u32>(SlotType::Max(0)).unwrap_err();
+    assert!(err.to_string().starts_with("Invalid store parameter"));
+
+    assert!(system.register_store::<u32>(SlotType::Sample(0)).is_err());
+    assert!(system
+        .register_store::<u32>(SlotType::Reservoir(0))
+        .is_err());
+    assert!(system
+        .register_store::<u32>(SlotType::ReservoirSeeded(0, 1))
+        .is_err());
+    assert!(system
+        .register_store::<u32>(SlotType::Top(0, u32::cmp))
+        .is_err());
+    assert!(system.register_store_lru::<u64>(0).is_err());
+    assert!(system.register_store_inline::<u16, 0>().is_err());
+
+    // nothing got registered
+    assert!(system.new_event::<u32>(1).is_err());
+    assert!(system.new_event::<u64>(1).is_err());
+    assert!(system.new_event::<u16>(1).is_err());
+
+    // large capacities only preallocate a bounded amount
+    system
+        .register_store::<u32>(SlotType::Max(usize::MAX))
+        .unwrap();
+    system.register_store_lru::<u64>(usize::MAX).unwrap();
+}
+
+#[test]
+fn test_register_diagnostics() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::Max(2)).unwrap();
+    system.register_listener::<u32>(|_| {}).unwrap();
+    system.register_store::<u64>(SlotType::All).unwrap();
+    system.register_listener::<u8>(|_| {}).unwrap();
+    system.register_listener::<u8>(|_| {}).unwrap();
+
+    for i in 0..3 {
+        system.new_event::<u32>(i).unwrap();
+    }
+    system.disable::<u64>().unwrap();
+
+    let diagnostics = system.diagnostics();
+    assert_eq!(diagnostics.types.len(), 3);
+
+    assert_eq!(
+        diagnostics.get(TypeId::of::<u32>()),
+        Some(&TypeDiagnostics {
+            type_id: TypeId::of::<u32>(),
+            type_name: "u32",
+            slot: Some("Max"),
+            len: Some(2),
+            capacity: Some(2),
+            enabled: true,
+            listeners: 1,
+            dropped: Some(1),
+        })
+    );
+
+    let typ = diagnostics.get(TypeId::of::<u64>()).unwrap();
+    assert_eq!(typ.slot, Some("All"));
+    assert_eq!(typ.capacity, None);
+    assert!(!typ.enabled);
+
+    let typ = diagnostics.get(TypeId::of::<u8>()).unwrap();
+    assert_eq!(typ.slot, None);
+    assert_eq!(typ.len, None);
+    assert_eq!(typ.listeners, 2);
+
+    assert!(diagnostics.get(TypeId::of::<i8>()).is_none());
+}
+
+#[test]
+fn test_register_subscribe_store() {
+    let mut system = EventBackend::default();
+
+    let store = system.subscribe_store::<u32>(SlotType::All).unwrap();
+    assert_eq!(store.type_id(), TypeId::of::<u32>());
+
+    assert!(system.subscribe_store::<u64>(SlotType::Max(0)).is_err());
+
+    let copy = store;
+    store.new_event(&system, 1).unwrap();
+    copy.new_event(&system, 2).unwrap();
+    assert_eq!(store.len(&system).unwrap(), 2);
+
+    assert_eq!(store.query(&system).unwrap().collect::<Vec<_>>(), [1, 2]);
+
+    store.new_event(&system, 3).unwrap();
+    assert_eq!(
+        store.query_blocking(&system).unwrap().collect::<Vec<_>>(),
+        [3]
+    );
+}
+
+#[test]
+fn test_register_is_registered() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_listener::<u64>(|_: &u64| {}).unwrap();
+
+    assert!(system.is_registered::<u32>());
+    assert!(system.is_registered::<u64>());
+    assert!(!system.is_registered::<i8>());
+
+    assert!(system.is_store_registered::<u32>());
+    assert!(!system.is_store_registered::<u64>());
+    assert!(!system.is_store_registered::<i8>());
+
+    // adding a store to a listener only type
+    system.register_store::<u64>(SlotType::Last).unwrap();
+    assert!(system.is_store_registered::<u64>());
+
+    assert!(system.unregister::<u32>().unwrap().is_empty());
+    assert!(!system.is_registered::<u32>());
+}
+
+#[test]
+fn test_register_registered_types() {
+    let mut system = EventBackend::default();
+
+    assert_eq!(system.registered_type_count(), 0);
+    assert_eq!(system.registered_types().count(), 0);
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_listener::<u64>(|_: &u64| {}).unwrap();
+    system.register_store::<u64>(SlotType::Last).unwrap();
+
+    // a type with a store and a listener is only registered once
+    assert_eq!(system.registered_type_count(), 2);
+    assert_eq!(
+        system.registered_types().collect::<Vec<_>>(),
+        [TypeId::of::<u32>(), TypeId::of::<u64>()]
+    );
+
+    system.unregister::<u32>().unwrap();
+    assert_eq!(
+        system.registered_types().collect::<Vec<_>>(),
+        [TypeId::of::<u64>()]
+    );
+}
+
+#[test]
+fn test_register_type_info() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.register_listener::<u64>(|_: &u64| {}).unwrap();
+    system.register_store::<i8>(SlotType::Last).unwrap();
+    system.disable::<i8>().unwrap();
+
+    system.new_event::<u32>(1).unwrap();
+
+    assert_eq!(system.registered_types().count(), 3);
+
+    let infos = system
+        .registered_types()
+        .map(|id| system.type_info(id).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(infos[0].len, Some(1));
+    assert_eq!(infos[1].slot, None);
+    assert_eq!(infos[1].listeners, 1);
+    assert!(infos[1].enabled);
+    assert!(!infos[2].enabled);
+
+    assert!(system.type_info(TypeId::of::<u16>()).is_none());
+}
+
+#[test]
+fn test_register_builder() {
+    let system = EventBackendBuilder::default()
+        .store::<u32>(SlotType::All)
+        .listener::<u64>(|_: &u64| {})
+        .build()
+        .unwrap();
+
+    assert!(system.is_store_registered::<u32>());
+    assert_eq!(system.listener_count::<u64>().unwrap(), 1);
+
+    // the first error is returned by build
+    let err = EventBackendBuilder::default()
+        .store::<u32>(SlotType::Max(0))
+        .store::<[u64; 64]>(SlotType::All)
+        .build()
+        .unwrap_err();
+    assert!(err.to_string().starts_with("Invalid store parameter"));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "serde")]
+
+use eventsys::{EventBackend, SlotType};
+
+#[test]
+fn test_serde_pending() {
+    let mut system = EventBackend::default();
+
+    system.register_store::<u32>(SlotType::All).unwrap();
+    system.new_events::<u32>(0..5).unwrap();
+
+    let mut json = Vec::new();
+    system
+        .serialize_pending::<u32, _>(&mut serde_json::Serializer::new(&mut json))
+        .unwrap();
+
+    let events: Vec<u32> = serde_json::from_slice(&json).unwrap();
+    assert_eq!(events, [0, 1, 2, 3, 4]);
+
+    // serializing did not consume events
+    assert_eq!(system.query::<u32>().unwrap().collect::<Vec<_>>(), events);
+
+    let mut json = Vec::new();
+    assert!(system
+        .serialize_pending::<u64, _>(&mut serde_json::Serializer::new(&mut json))
+        .is_err());
+}